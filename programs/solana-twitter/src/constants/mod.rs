@@ -2,9 +2,32 @@ pub const ANCHOR_DISCRIMINATOR_SIZE: usize = 8;
 
 // Platform constants
 pub const MIN_DONATION_AMOUNT: u64 = 20_000_000; // 0.02 SOL in lamports
+pub const FEE_BASIS_POINTS: u64 = 10_000; // 100% expressed in basis points
+pub const DEFAULT_PLATFORM_FEE_BPS: u64 = 500; // 5%
+pub const MAX_PLATFORM_FEE_BPS: u64 = 1_000; // 10%
+pub const MIN_USERNAME_LENGTH: usize = 3;
 pub const MAX_USERNAME_LENGTH: usize = 20;
+pub const USERNAME_CHANGE_COOLDOWN: u64 = 30 * 24 * 60 * 60; // 30 days in seconds
 pub const MAX_DISPLAY_NAME_LENGTH: usize = 50;
 pub const MAX_BIO_LENGTH: usize = 160;
 pub const MAX_POST_CONTENT_LENGTH: usize = 280;
 pub const MAX_COMMENT_LENGTH: usize = 140;
-pub const MAX_IMAGE_URL_LENGTH: usize = 256;
\ No newline at end of file
+pub const MAX_DONATION_MESSAGE_LENGTH: usize = 140;
+pub const MAX_IMAGE_URL_LENGTH: usize = 256;
+pub const MAX_COMMENT_DEPTH: u8 = 4; // top-level comments are depth 0
+pub const MAX_COLLABORATORS: usize = 5; // co-authors per collaboration post
+pub const REACTION_KIND_COUNT: usize = 5; // variants of ReactionKind
+
+// Handles that cannot be claimed by users
+pub const RESERVED_USERNAMES: &[&str] = &[
+    "admin",
+    "administrator",
+    "moderator",
+    "official",
+    "platform",
+    "root",
+    "solana",
+    "support",
+    "system",
+    "twitter",
+];
\ No newline at end of file
@@ -8,6 +8,15 @@ pub enum ErrorCode {
     #[msg("Username is too long")]
     UsernameTooLong,
     
+    #[msg("Username is too short")]
+    UsernameTooShort,
+    
+    #[msg("Username may only contain a-z, 0-9 and underscore")]
+    InvalidUsernameCharacters,
+    
+    #[msg("Username is reserved")]
+    UsernameReserved,
+    
     #[msg("Display name is too long")]
     DisplayNameTooLong,
     
@@ -26,6 +35,12 @@ pub enum ErrorCode {
     #[msg("Username already exists")]
     UsernameAlreadyExists,
     
+    #[msg("New username is the same as the current one")]
+    UsernameUnchanged,
+    
+    #[msg("Username was changed too recently")]
+    UsernameChangeCooldown,
+    
     #[msg("Profile not found")]
     ProfileNotFound,
     
@@ -35,6 +50,18 @@ pub enum ErrorCode {
     #[msg("Comment not found")]
     CommentNotFound,
     
+    #[msg("Parent comment does not belong to this post")]
+    ParentCommentMismatch,
+    
+    #[msg("Comment thread is nested too deeply")]
+    CommentDepthExceeded,
+    
+    #[msg("Only the comment author can perform this action")]
+    NotCommentAuthor,
+    
+    #[msg("Only the post author or collaborator can perform this action")]
+    NotPostOwner,
+    
     #[msg("Cannot follow yourself")]
     CannotFollowSelf,
     
@@ -50,6 +77,15 @@ pub enum ErrorCode {
     #[msg("Not liked post")]
     NotLiked,
     
+    #[msg("Not reposted post")]
+    NotReposted,
+    
+    #[msg("Already reacted with this reaction")]
+    AlreadyReacted,
+    
+    #[msg("Not reacted to post")]
+    NotReacted,
+    
     #[msg("Unauthorized action")]
     Unauthorized,
     
@@ -59,12 +95,69 @@ pub enum ErrorCode {
     #[msg("Cannot donate to yourself")]
     CannotDonateToSelf,
     
+    #[msg("Donation message is too long")]
+    DonationMessageTooLong,
+    
+    #[msg("Donation summary must be passed for named donations only")]
+    DonationSummaryMismatch,
+    
+    #[msg("Creator wallet does not match the creator")]
+    InvalidCreatorWallet,
+    
+    #[msg("Account does not match the platform fee recipient")]
+    InvalidPlatformFeeRecipient,
+    
     #[msg("Collaborator not found")]
     CollaboratorNotFound,
     
+    #[msg("Only the invited collaborator can respond to this invite")]
+    NotInvitedCollaborator,
+    
+    #[msg("Collaboration invite is not pending")]
+    CollaborationNotPending,
+    
+    #[msg("Too many collaborators")]
+    TooManyCollaborators,
+    
+    #[msg("Collaborator listed more than once")]
+    DuplicateCollaborator,
+    
+    #[msg("Collaborator shares exceed 100%")]
+    InvalidRevenueShare,
+    
+    #[msg("Collaborator wallet and profile accounts do not match the post")]
+    CollaboratorAccountsMismatch,
+    
     #[msg("Post is deleted")]
     PostDeleted,
     
     #[msg("Cannot delete someone else's post")]
     CannotDeleteOthersPost,
+    
+    #[msg("Cannot edit someone else's post")]
+    CannotEditOthersPost,
+    
+    #[msg("Post still has comments")]
+    PostHasComments,
+    
+    #[msg("Revision accounts do not match the post's edit history")]
+    RevisionAccountsMismatch,
+    
+    #[msg("Only the program admin can perform this action")]
+    UnauthorizedAdmin,
+    
+    #[msg("Platform fee exceeds the maximum allowed")]
+    PlatformFeeTooHigh,
+    
+    #[msg("No admin transfer is pending for this signer")]
+    NoPendingAdmin,
+    
+    #[msg("Arithmetic overflow")]
+    MathOverflow,
+    
+    #[msg("Program is paused")]
+    ProgramPaused,
+    
+    #[msg("Treasury balance is too low for this withdrawal")]
+    InsufficientTreasuryBalance,
 }
\ No newline at end of file
@@ -0,0 +1,10 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct TreasuryWithdrawn {
+    pub admin: Pubkey,
+    pub recipient: Pubkey,
+    pub mint: Option<Pubkey>, // None for SOL withdrawals
+    pub amount: u64,
+    pub timestamp: u64,
+}
\ No newline at end of file
@@ -0,0 +1,30 @@
+use crate::errors::ErrorCode::*;
+use crate::states::ProgramState;
+use anchor_lang::prelude::*;
+
+pub fn accept_admin(ctx: Context<AcceptAdminCtx>) -> Result<()> {
+    let state = &mut ctx.accounts.program_state;
+    let new_admin = ctx.accounts.new_admin.key();
+
+    // Signer must be the proposed admin
+    if state.pending_admin != Some(new_admin) {
+        return Err(NoPendingAdmin.into());
+    }
+
+    state.admin = new_admin;
+    state.pending_admin = None;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AcceptAdminCtx<'info> {
+    #[account(
+        mut,
+        seeds = [b"program_state"],
+        bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    pub new_admin: Signer<'info>,
+}
\ No newline at end of file
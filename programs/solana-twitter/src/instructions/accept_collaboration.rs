@@ -0,0 +1,70 @@
+use crate::errors::ErrorCode::*;
+use crate::states::{Post, UserProfile, ProgramState, CollaborationStatus, PauseCategory};
+use anchor_lang::prelude::*;
+
+pub fn accept_collaboration(ctx: Context<AcceptCollaborationCtx>, post_id: u64) -> Result<()> {
+    // Reject while the program or posting is paused
+    ctx.accounts.program_state.require_not_paused(PauseCategory::Posting)?;
+
+    let post = &mut ctx.accounts.post;
+    let collaborator_profile = &mut ctx.accounts.collaborator_profile;
+
+    // Verify post exists and matches ID
+    if post.post_id != post_id {
+        return Err(PostNotFound.into());
+    }
+
+    // Cannot join a deleted post
+    if post.is_deleted {
+        return Err(PostDeleted.into());
+    }
+
+    // Only an invited collaborator can accept, and only their own invite
+    let Some(entry) = post.collaborator_mut(&ctx.accounts.collaborator.key()) else {
+        return Err(NotInvitedCollaborator.into());
+    };
+
+    // Invite must still be open
+    if entry.status != CollaborationStatus::Pending {
+        return Err(CollaborationNotPending.into());
+    }
+
+    // Accepted collaborations count toward the collaborator's posts
+    entry.status = CollaborationStatus::Accepted;
+    post.is_collaboration = true;
+    collaborator_profile.posts_count += 1;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(post_id: u64)]
+pub struct AcceptCollaborationCtx<'info> {
+    #[account(
+        seeds = [b"program_state"],
+        bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    #[account(
+        mut,
+        seeds = [
+            b"post",
+            post_id.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub post: Account<'info, Post>,
+    
+    #[account(
+        mut,
+        seeds = [
+            b"user_profile",
+            collaborator.key().as_ref()
+        ],
+        bump
+    )]
+    pub collaborator_profile: Account<'info, UserProfile>,
+    
+    pub collaborator: Signer<'info>,
+}
\ No newline at end of file
@@ -0,0 +1,98 @@
+use crate::constants::*;
+use crate::errors::ErrorCode::*;
+use crate::states::{ProgramState, UserProfile, UsernameRecord, PauseCategory};
+use crate::validation::{normalize_username, username_seed, validate_username};
+use anchor_lang::prelude::*;
+
+pub fn change_username(ctx: Context<ChangeUsernameCtx>, new_username: String) -> Result<()> {
+    // Reject while the program or social features are paused
+    ctx.accounts.program_state.require_not_paused(PauseCategory::Social)?;
+
+    let profile = &mut ctx.accounts.user_profile;
+    let old_record = &ctx.accounts.old_username_record;
+    let new_record = &mut ctx.accounts.new_username_record;
+    let now = Clock::get()?.unix_timestamp as u64;
+
+    // Verify user owns the profile and its current handle
+    if profile.owner != ctx.accounts.user.key() || old_record.owner != profile.owner {
+        return Err(Unauthorized.into());
+    }
+
+    // Validate the new handle
+    let new_username = normalize_username(&new_username);
+    validate_username(&new_username)?;
+    if new_username == profile.username {
+        return Err(UsernameUnchanged.into());
+    }
+
+    // Prevent handles from being churned for squatting
+    if profile.username_changed_at != 0
+        && now < profile.username_changed_at + USERNAME_CHANGE_COOLDOWN
+    {
+        return Err(UsernameChangeCooldown.into());
+    }
+
+    // New handle must not already be claimed
+    if new_record.owner != Pubkey::default() {
+        return Err(UsernameAlreadyExists.into());
+    }
+
+    // Claim the new handle; the old record is closed back to the user
+    new_record.owner = profile.owner;
+    new_record.profile = profile.key();
+    new_record.username = new_username.clone();
+    new_record.created_at = now;
+
+    profile.username = new_username;
+    profile.username_changed_at = now;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(new_username: String)]
+pub struct ChangeUsernameCtx<'info> {
+    #[account(
+        seeds = [b"program_state"],
+        bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    #[account(
+        mut,
+        seeds = [
+            b"user_profile",
+            user.key().as_ref()
+        ],
+        bump
+    )]
+    pub user_profile: Account<'info, UserProfile>,
+    
+    #[account(
+        mut,
+        close = user,
+        seeds = [
+            b"username",
+            username_seed(&user_profile.username).as_ref()
+        ],
+        bump
+    )]
+    pub old_username_record: Account<'info, UsernameRecord>,
+    
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = ANCHOR_DISCRIMINATOR_SIZE + UsernameRecord::INIT_SPACE,
+        seeds = [
+            b"username",
+            username_seed(&new_username).as_ref()
+        ],
+        bump
+    )]
+    pub new_username_record: Account<'info, UsernameRecord>,
+    
+    #[account(mut)]
+    pub user: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
\ No newline at end of file
@@ -1,18 +1,24 @@
 use crate::constants::*;
 use crate::errors::ErrorCode::*;
-use crate::states::{ProgramState, UserProfile, Post};
+use crate::states::{
+    ProgramState, UserProfile, Post, Collaborator, CollaboratorInvite, CollaborationStatus,
+    PauseCategory,
+};
 use anchor_lang::prelude::*;
 
 pub fn create_collaboration_post(
     ctx: Context<CreateCollaborationPostCtx>,
-    collaborator: Pubkey,
+    collaborators: Vec<CollaboratorInvite>,
     content: String,
     image_url: Option<String>,
 ) -> Result<()> {
+    // Reject while the program or posting is paused
+    ctx.accounts.program_state.require_not_paused(PauseCategory::Posting)?;
+
     let post = &mut ctx.accounts.post;
     let state = &mut ctx.accounts.program_state;
     let author_profile = &mut ctx.accounts.author_profile;
-    let collaborator_profile = &ctx.accounts.collaborator_profile;
+    let author = ctx.accounts.author.key();
 
     // Validate content length
     if content.len() > MAX_POST_CONTENT_LENGTH {
@@ -26,38 +32,60 @@ pub fn create_collaboration_post(
         }
     }
 
-    // Verify collaborator profile exists
-    if collaborator_profile.owner != collaborator {
+    // Need at least one co-author, within the per-post limit
+    if collaborators.is_empty() {
         return Err(CollaboratorNotFound.into());
     }
+    if collaborators.len() > MAX_COLLABORATORS {
+        return Err(TooManyCollaborators.into());
+    }
+
+    let mut total_share_bps: u64 = 0;
+    for (i, collaborator) in collaborators.iter().enumerate() {
+        // Cannot collaborate with yourself
+        if collaborator.key == author {
+            return Err(CannotFollowSelf.into()); // Reusing error for self-action
+        }
+
+        if collaborators[..i].iter().any(|c| c.key == collaborator.key) {
+            return Err(DuplicateCollaborator.into());
+        }
+
+        total_share_bps += collaborator.share_bps as u64;
+    }
 
-    // Cannot collaborate with yourself
-    if ctx.accounts.author.key() == collaborator {
-        return Err(CannotFollowSelf.into()); // Reusing error for self-action
+    // Collaborator shares cannot exceed the whole tip
+    if total_share_bps > FEE_BASIS_POINTS {
+        return Err(InvalidRevenueShare.into());
     }
 
     // Increment counters
     state.post_count += 1;
     author_profile.posts_count += 1;
 
-    // Initialize collaboration post
-    post.post_id = state.post_count;
-    post.author = ctx.accounts.author.key();
-    post.collaborator = Some(collaborator);
-    post.content = content;
-    post.image_url = image_url;
-    post.likes_count = 0;
-    post.comments_count = 0;
-    post.created_at = Clock::get()?.unix_timestamp as u64;
-    post.updated_at = post.created_at;
-    post.is_deleted = false;
-    post.is_collaboration = true;
+    // Initialize collaboration post with every co-author pending; it only
+    // becomes a collaboration once one of them accepts. Collaborator
+    // profiles are checked when they accept
+    post.initialize(
+        state.post_count,
+        author,
+        content,
+        image_url,
+        Clock::get()?.unix_timestamp as u64,
+    );
+    post.collaborators = collaborators
+        .into_iter()
+        .map(|invite| Collaborator {
+            key: invite.key,
+            status: CollaborationStatus::Pending,
+            share_bps: invite.share_bps,
+        })
+        .collect();
 
     Ok(())
 }
 
 #[derive(Accounts)]
-#[instruction(collaborator: Pubkey)]
 pub struct CreateCollaborationPostCtx<'info> {
     #[account(mut)]
     pub program_state: Account<'info, ProgramState>,
@@ -72,15 +100,6 @@ pub struct CreateCollaborationPostCtx<'info> {
     )]
     pub author_profile: Account<'info, UserProfile>,
     
-    #[account(
-        seeds = [
-            b"user_profile",
-            collaborator.as_ref()
-        ],
-        bump
-    )]
-    pub collaborator_profile: Account<'info, UserProfile>,
-    
     #[account(
         init,
         payer = author,
@@ -1,14 +1,17 @@
 use crate::constants::*;
 use crate::errors::ErrorCode::*;
-use crate::states::{ProgramState, Comment, Post};
+use crate::states::{ProgramState, Comment, Post, PauseCategory, COMMENT_SEED};
 use anchor_lang::prelude::*;
 
 pub fn create_comment(
     ctx: Context<CreateCommentCtx>,
     post_id: u64,
-    comment_id: u64,
     content: String,
+    parent_comment_id: Option<u64>,
 ) -> Result<()> {
+    // Reject while the program or posting is paused
+    ctx.accounts.program_state.require_not_paused(PauseCategory::Posting)?;
+
     let comment = &mut ctx.accounts.comment;
     let post = &mut ctx.accounts.post;
     let state = &mut ctx.accounts.program_state;
@@ -28,51 +31,96 @@ pub fn create_comment(
         return Err(CommentTooLong.into());
     }
 
-    
+    // Resolve the parent comment when replying within a thread
+    let depth = match (parent_comment_id, ctx.accounts.parent_comment.as_ref()) {
+        (None, None) => 0,
+        (Some(parent_id), Some(parent_info)) => {
+            // A deleted parent's account is closed, so there is nothing to reply to
+            if parent_info.data_is_empty() || *parent_info.owner != crate::ID {
+                return Err(CommentNotFound.into());
+            }
+
+            let mut data = parent_info.try_borrow_mut_data()?;
+            let mut parent = Comment::try_deserialize(&mut &data[..])?;
+
+            if parent.comment_id != parent_id || parent.post_id != post_id {
+                return Err(ParentCommentMismatch.into());
+            }
+
+            if parent.depth >= MAX_COMMENT_DEPTH {
+                return Err(CommentDepthExceeded.into());
+            }
+
+            parent.replies_count += 1;
+            parent.try_serialize(&mut &mut data[..])?;
+            parent.depth + 1
+        }
+        _ => return Err(ParentCommentMismatch.into()),
+    };
 
-    // Update counters
-    state.comment_count = comment_id;
-    post.comments_count = post.comments_count + 1;
+    // Update counters; comment IDs are assigned per post
+    state.comment_count += 1;
+    post.comment_id_counter += 1;
+    post.comments_count += 1;
 
     // Initialize comment
-    comment.comment_id = comment_id;
+    comment.comment_id = post.comment_id_counter;
     comment.post_id = post_id;
     comment.author = ctx.accounts.user.key();
+    comment.parent_comment_id = parent_comment_id;
+    comment.depth = depth;
     comment.content = content;
+    comment.likes_count = 0;
+    comment.replies_count = 0;
     comment.created_at = Clock::get()?.unix_timestamp as u64;
-    comment.is_deleted = false;
+    comment.edited_at = comment.created_at;
+    comment.is_edited = false;
+    comment.is_hidden = false;
 
     Ok(())
 }
 
 #[derive(Accounts)]
-#[instruction(post_id: u64, comment_id: u64)]
+#[instruction(post_id: u64, content: String, parent_comment_id: Option<u64>)]
 pub struct CreateCommentCtx<'info> {
     #[account(mut)]
     pub program_state: Account<'info, ProgramState>,
 
+    #[account(
+        mut,
+        seeds = [
+            b"post",
+            post_id.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub post: Account<'info, Post>,
+
     #[account(
         init,
         payer = user,
         space = ANCHOR_DISCRIMINATOR_SIZE + Comment::INIT_SPACE,
         seeds = [
-            b"comment",
+            COMMENT_SEED,
             post_id.to_le_bytes().as_ref(),
-            comment_id.to_le_bytes().as_ref()
+            (post.comment_id_counter + 1).to_le_bytes().as_ref()
         ],
         bump
     )]
     pub comment: Account<'info, Comment>,
 
+    /// CHECK: the comment being replied to, which may already be closed by
+    /// delete_comment; deserialized in the handler only when it still exists
     #[account(
         mut,
         seeds = [
-            b"post",
-            post_id.to_le_bytes().as_ref()
+            COMMENT_SEED,
+            post_id.to_le_bytes().as_ref(),
+            parent_comment_id.unwrap_or_default().to_le_bytes().as_ref()
         ],
         bump
     )]
-    pub post: Account<'info, Post>,
+    pub parent_comment: Option<UncheckedAccount<'info>>,
 
     #[account(mut)]
     pub user: Signer<'info>,
@@ -1,6 +1,6 @@
 use crate::constants::*;
 use crate::errors::ErrorCode::*;
-use crate::states::{ProgramState, UserProfile, Post};
+use crate::states::{ProgramState, UserProfile, Post, PauseCategory};
 use anchor_lang::prelude::*;
 
 pub fn create_post(
@@ -8,6 +8,9 @@ pub fn create_post(
     content: String,
     image_url: Option<String>,
 ) -> Result<()> {
+    // Reject while the program or posting is paused
+    ctx.accounts.program_state.require_not_paused(PauseCategory::Posting)?;
+
     let post = &mut ctx.accounts.post;
     let state = &mut ctx.accounts.program_state;
     let user_profile = &mut ctx.accounts.user_profile;
@@ -29,17 +32,13 @@ pub fn create_post(
     user_profile.posts_count += 1;
 
     // Initialize post
-    post.post_id = state.post_count;
-    post.author = ctx.accounts.user.key();
-    post.collaborator = None;
-    post.content = content;
-    post.image_url = image_url;
-    post.likes_count = 0;
-    post.comments_count = 0;
-    post.created_at = Clock::get()?.unix_timestamp as u64;
-    post.updated_at = post.created_at;
-    post.is_deleted = false;
-    post.is_collaboration = false;
+    post.initialize(
+        state.post_count,
+        ctx.accounts.user.key(),
+        content,
+        image_url,
+        Clock::get()?.unix_timestamp as u64,
+    );
 
     Ok(())
 }
@@ -1,6 +1,7 @@
 use crate::constants::*;
 use crate::errors::ErrorCode::*;
-use crate::states::{ProgramState, UserProfile};
+use crate::states::{ProgramState, UserProfile, UsernameRecord, PauseCategory};
+use crate::validation::{normalize_username, username_seed, validate_username};
 use anchor_lang::prelude::*;
 
 pub fn create_profile(
@@ -10,13 +11,18 @@ pub fn create_profile(
     bio: String,
     profile_image_url: String,
 ) -> Result<()> {
+    // Reject while the program or social features are paused
+    ctx.accounts.program_state.require_not_paused(PauseCategory::Social)?;
+
     let profile = &mut ctx.accounts.user_profile;
+    let username_record = &mut ctx.accounts.username_record;
     let state = &mut ctx.accounts.program_state;
 
+    // Handles are stored case-folded and must match the handle grammar
+    let username = normalize_username(&username);
+    validate_username(&username)?;
+
     // Validate input lengths
-    if username.len() > MAX_USERNAME_LENGTH {
-        return Err(UsernameTooLong.into());
-    }
     if display_name.len() > MAX_DISPLAY_NAME_LENGTH {
         return Err(DisplayNameTooLong.into());
     }
@@ -27,6 +33,11 @@ pub fn create_profile(
         return Err(ImageUrlTooLong.into());
     }
 
+    // Handle must not already be claimed
+    if username_record.owner != Pubkey::default() {
+        return Err(UsernameAlreadyExists.into());
+    }
+
     // Increment user count and assign user ID
     state.user_count += 1;
     
@@ -42,7 +53,15 @@ pub fn create_profile(
     profile.posts_count = 0;
     profile.created_at = Clock::get()?.unix_timestamp as u64;
     profile.total_donations_received = 0;
+    profile.donations_received_count = 0;
     profile.is_verified = false;
+    profile.username_changed_at = 0;
+
+    // Claim the handle for this profile
+    username_record.owner = profile.owner;
+    username_record.profile = profile.key();
+    username_record.username = profile.username.clone();
+    username_record.created_at = profile.created_at;
 
     Ok(())
 }
@@ -65,6 +84,18 @@ pub struct CreateProfileCtx<'info> {
     )]
     pub user_profile: Account<'info, UserProfile>,
     
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = ANCHOR_DISCRIMINATOR_SIZE + UsernameRecord::INIT_SPACE,
+        seeds = [
+            b"username",
+            username_seed(&username).as_ref()
+        ],
+        bump
+    )]
+    pub username_record: Account<'info, UsernameRecord>,
+    
     #[account(mut)]
     pub user: Signer<'info>,
     
@@ -1,58 +1,105 @@
 use crate::errors::ErrorCode::*;
-use crate::states::{Comment, Post};
+use crate::states::{Comment, Post, ProgramState, PauseCategory, COMMENT_SEED};
 use anchor_lang::prelude::*;
 
-pub fn delete_comment(ctx: Context<DeleteCommentCtx>, comment_id: u64) -> Result<()> {
-    let comment = &mut ctx.accounts.comment;
+pub fn delete_comment(
+    ctx: Context<DeleteCommentCtx>,
+    post_id: u64,
+    comment_id: u64,
+) -> Result<()> {
+    // Reject while the program or posting is paused
+    ctx.accounts.program_state.require_not_paused(PauseCategory::Posting)?;
+
+    let comment = &ctx.accounts.comment;
     let post = &mut ctx.accounts.post;
 
     // Verify comment exists and matches ID
-    if comment.comment_id != comment_id {
+    if comment.comment_id != comment_id || comment.post_id != post_id {
         return Err(CommentNotFound.into());
     }
 
-    // Verify user is the author of the comment
-    if comment.author != ctx.accounts.user.key() {
-        return Err(Unauthorized.into());
-    }
-
-    // Check if comment is already deleted
-    if comment.is_deleted {
-        return Err(CommentNotFound.into());
+    // Comment author or post owner (moderation) may delete
+    let user = ctx.accounts.user.key();
+    if comment.author != user && !post.is_owner(&user) {
+        return Err(NotCommentAuthor.into());
     }
 
-    // Mark comment as deleted
-    comment.is_deleted = true;
-
     // Decrement post's comment count
     post.comments_count = post.comments_count.saturating_sub(1);
 
+    // Keep the parent's reply counter in step; a parent that was itself
+    // deleted has no counter left to update
+    if comment.parent_comment_id.is_some() {
+        let Some(parent_info) = ctx.accounts.parent_comment.as_ref() else {
+            return Err(ParentCommentMismatch.into());
+        };
+
+        if !parent_info.data_is_empty() {
+            if *parent_info.owner != crate::ID {
+                return Err(ParentCommentMismatch.into());
+            }
+
+            let mut data = parent_info.try_borrow_mut_data()?;
+            let mut parent = Comment::try_deserialize(&mut &data[..])?;
+            parent.replies_count = parent.replies_count.saturating_sub(1);
+            parent.try_serialize(&mut &mut data[..])?;
+        }
+    }
+
     Ok(())
 }
 
 #[derive(Accounts)]
-#[instruction(comment_id: u64)]
+#[instruction(post_id: u64, comment_id: u64)]
 pub struct DeleteCommentCtx<'info> {
+    #[account(
+        seeds = [b"program_state"],
+        bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
     #[account(
         mut,
+        close = comment_author,
         seeds = [
-            b"comment",
+            COMMENT_SEED,
+            post_id.to_le_bytes().as_ref(),
             comment_id.to_le_bytes().as_ref()
         ],
         bump
     )]
     pub comment: Account<'info, Comment>,
     
+    /// CHECK: the parent comment PDA for replies, derived from
+    /// comment.parent_comment_id; it may already be closed
+    #[account(
+        mut,
+        seeds = [
+            COMMENT_SEED,
+            post_id.to_le_bytes().as_ref(),
+            comment.parent_comment_id.unwrap_or_default().to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub parent_comment: Option<UncheckedAccount<'info>>,
+    
     #[account(
         mut,
         seeds = [
             b"post",
-            comment.post_id.to_le_bytes().as_ref()
+            post_id.to_le_bytes().as_ref()
         ],
         bump
     )]
     pub post: Account<'info, Post>,
     
+    // Receives the rent even when a post owner removes the comment
+    #[account(
+        mut,
+        address = comment.author @ Unauthorized
+    )]
+    pub comment_author: SystemAccount<'info>,
+    
     #[account(mut)]
     pub user: Signer<'info>,
 }
\ No newline at end of file
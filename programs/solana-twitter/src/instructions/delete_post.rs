@@ -1,19 +1,26 @@
 use crate::errors::ErrorCode::*;
-use crate::states::{Post, UserProfile};
+use crate::states::{Post, UserProfile, ProgramState, PauseCategory};
 use anchor_lang::prelude::*;
 
-pub fn delete_post(ctx: Context<DeletePostCtx>, post_id: u64) -> Result<()> {
+// Remaining accounts: the UserProfile of every accepted collaborator, in the
+// order they appear in post.collaborators
+pub fn delete_post<'info>(
+    ctx: Context<'_, '_, 'info, 'info, DeletePostCtx<'info>>,
+    post_id: u64,
+) -> Result<()> {
+    // Reject while the program or posting is paused
+    ctx.accounts.program_state.require_not_paused(PauseCategory::Posting)?;
+
     let post = &mut ctx.accounts.post;
-    let user_profile = &mut ctx.accounts.user_profile;
+    let author_profile = &mut ctx.accounts.author_profile;
 
     // Verify post exists and matches ID
     if post.post_id != post_id {
         return Err(PostNotFound.into());
     }
 
-    // Verify user is the author or collaborator
-    if post.author != ctx.accounts.user.key() && 
-       post.collaborator != Some(ctx.accounts.user.key()) {
+    // Verify user is the author or an accepted collaborator
+    if !post.is_owner(&ctx.accounts.user.key()) {
         return Err(CannotDeleteOthersPost.into());
     }
 
@@ -26,9 +33,72 @@ pub fn delete_post(ctx: Context<DeletePostCtx>, post_id: u64) -> Result<()> {
     post.is_deleted = true;
     post.updated_at = Clock::get()?.unix_timestamp as u64;
 
-    // Decrement user's post count only if they are the original author
-    if post.author == ctx.accounts.user.key() {
-        user_profile.posts_count = user_profile.posts_count.saturating_sub(1);
+    // The post leaves the author's count and every co-author's, whoever deletes it
+    author_profile.posts_count = author_profile.posts_count.saturating_sub(1);
+    release_collaborator_posts(post, ctx.remaining_accounts)?;
+
+    // A deleted reply or quote no longer counts toward the post it points at
+    release_related_post(post.reply_to, ctx.accounts.parent_post.as_ref(), |p| {
+        &mut p.replies_count
+    })?;
+    release_related_post(post.quote_of, ctx.accounts.quoted_post.as_ref(), |p| {
+        &mut p.quotes_count
+    })?;
+
+    Ok(())
+}
+
+// Take the post back out of a counter on the post it points at (reply parent
+// or quoted original). That post may have been purged since, leaving no
+// counter to update
+pub(crate) fn release_related_post(
+    related_post_id: Option<u64>,
+    related: Option<&UncheckedAccount>,
+    counter: fn(&mut Post) -> &mut u64,
+) -> Result<()> {
+    if related_post_id.is_none() {
+        return Ok(());
+    }
+
+    let Some(info) = related else {
+        return Err(PostNotFound.into());
+    };
+    if info.data_is_empty() {
+        return Ok(());
+    }
+    if *info.owner != crate::ID {
+        return Err(PostNotFound.into());
+    }
+
+    let mut data = info.try_borrow_mut_data()?;
+    let mut related_post = Post::try_deserialize(&mut &data[..])?;
+    let count = counter(&mut related_post);
+    *count = count.saturating_sub(1);
+    related_post.try_serialize(&mut &mut data[..])?;
+
+    Ok(())
+}
+
+// Undo accept_collaboration's posts_count increment for every accepted
+// collaborator; `profiles` holds their UserProfile accounts in invite order
+pub(crate) fn release_collaborator_posts<'info>(
+    post: &Post,
+    profiles: &'info [AccountInfo<'info>],
+) -> Result<()> {
+    let accepted: Vec<_> = post.accepted_collaborators().collect();
+    if profiles.len() != accepted.len() {
+        return Err(CollaboratorAccountsMismatch.into());
+    }
+
+    for (collaborator, info) in accepted.iter().zip(profiles) {
+        let mut profile = Account::<UserProfile>::try_from(info)?;
+
+        if profile.owner != collaborator.key {
+            return Err(CollaboratorAccountsMismatch.into());
+        }
+
+        profile.posts_count = profile.posts_count.saturating_sub(1);
+        profile.exit(&crate::ID)?;
     }
 
     Ok(())
@@ -37,6 +107,12 @@ pub fn delete_post(ctx: Context<DeletePostCtx>, post_id: u64) -> Result<()> {
 #[derive(Accounts)]
 #[instruction(post_id: u64)]
 pub struct DeletePostCtx<'info> {
+    #[account(
+        seeds = [b"program_state"],
+        bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
     #[account(
         mut,
         seeds = [
@@ -47,15 +123,39 @@ pub struct DeletePostCtx<'info> {
     )]
     pub post: Account<'info, Post>,
     
+    /// CHECK: the post this one replies to, derived from post.reply_to; it
+    /// may already be purged
+    #[account(
+        mut,
+        seeds = [
+            b"post",
+            post.reply_to.unwrap_or_default().to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub parent_post: Option<UncheckedAccount<'info>>,
+    
+    /// CHECK: the post this one quotes, derived from post.quote_of; it may
+    /// already be purged
+    #[account(
+        mut,
+        seeds = [
+            b"post",
+            post.quote_of.unwrap_or_default().to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub quoted_post: Option<UncheckedAccount<'info>>,
+    
     #[account(
         mut,
         seeds = [
             b"user_profile",
-            user.key().as_ref()
+            post.author.as_ref()
         ],
         bump
     )]
-    pub user_profile: Account<'info, UserProfile>,
+    pub author_profile: Account<'info, UserProfile>,
     
     #[account(mut)]
     pub user: Signer<'info>,
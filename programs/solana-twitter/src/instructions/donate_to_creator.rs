@@ -1,17 +1,21 @@
 use crate::constants::*;
 use crate::errors::ErrorCode::*;
-use crate::states::{ProgramState, UserProfile, Donation};
+use crate::states::{ProgramState, UserProfile, Donation, DonationSummary, PauseCategory};
 use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
 
 pub fn donate_to_creator(
     ctx: Context<DonateToCreatorCtx>,
     creator: Pubkey,
     amount: u64,
+    message: Option<String>,
+    is_anonymous: bool,
 ) -> Result<()> {
-    // Get the donation key before borrowing mutably
-    let donation_key = ctx.accounts.donation.key();
-    
+    // Reject while the program or payments are paused
+    ctx.accounts.program_state.require_not_paused(PauseCategory::Payments)?;
+
     let donation = &mut ctx.accounts.donation;
+    let summary = &mut ctx.accounts.donation_summary;
     let creator_profile = &mut ctx.accounts.creator_profile;
     let state = &mut ctx.accounts.program_state;
     let donor = &ctx.accounts.donor;
@@ -31,53 +35,74 @@ pub fn donate_to_creator(
         return Err(ProfileNotFound.into());
     }
 
+    // Verify the destination wallet
+    if ctx.accounts.creator_wallet.key() != creator {
+        return Err(InvalidCreatorWallet.into());
+    }
+
+    // Validate the optional donor message
+    if let Some(ref text) = message {
+        if text.len() > MAX_DONATION_MESSAGE_LENGTH {
+            return Err(DonationMessageTooLong.into());
+        }
+    }
+
     // Calculate platform fee
-    let platform_fee = (amount * state.platform_fee) / 100;
+    let platform_fee = state.platform_fee_for(amount)?;
     let creator_amount = amount - platform_fee;
 
     // Transfer SOL to creator
-    let creator_transfer = anchor_lang::solana_program::system_instruction::transfer(
-        &donor.key(),
-        &creator,
+    transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: donor.to_account_info(),
+                to: ctx.accounts.creator_wallet.to_account_info(),
+            },
+        ),
         creator_amount,
-    );
-    
-    let creator_result = anchor_lang::solana_program::program::invoke(
-        &creator_transfer,
-        &[donor.to_account_info(), creator_profile.to_account_info()],
-    );
-
-    if let Err(e) = creator_result {
-        msg!("Creator donation transfer failed: {:?}", e);
-        return Err(e.into());
-    }
+    )?;
 
-    // Transfer platform fee if applicable
+    // Transfer platform fee into the treasury if applicable
     if platform_fee > 0 {
-        let platform_transfer = anchor_lang::solana_program::system_instruction::transfer(
-            &donor.key(),
-            &state.platform_address,
+        transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: donor.to_account_info(),
+                    to: ctx.accounts.treasury.to_account_info(),
+                },
+            ),
             platform_fee,
-        );
-        
-        let platform_result = anchor_lang::solana_program::program::invoke_signed(
-            &platform_transfer,
-            &[donor.to_account_info()],
-            &[],
-        );
+        )?;
+        state.total_fees_collected += platform_fee;
+    }
 
-        if let Err(e) = platform_result {
-            msg!("Platform fee transfer failed: {:?}", e);
-            return Err(e.into());
+    // Anonymous gifts stay out of the donor/creator summary entirely
+    let now = Clock::get()?.unix_timestamp as u64;
+    match (summary, is_anonymous) {
+        (Some(summary), false) => {
+            summary.donor = donor.key();
+            summary.recipient = creator;
+            summary.donation_count += 1;
+            summary.total_amount += amount;
+            summary.last_donation_at = now;
         }
+        (None, true) => {}
+        _ => return Err(DonationSummaryMismatch.into()),
     }
 
     // Record donation
-    donation.donor = donor.key();
+    creator_profile.donations_received_count += 1;
+    donation.donation_id = creator_profile.donations_received_count;
+    donation.donor = if is_anonymous { Pubkey::default() } else { donor.key() };
     donation.recipient = creator;
     donation.amount = amount;
-    donation.timestamp = Clock::get()?.unix_timestamp as u64;
-    donation.transaction_id = donation_key.to_string(); // Use the pre-captured key
+    donation.mint = None;
+    donation.post_id = None;
+    donation.timestamp = now;
+    donation.message = message;
+    donation.is_anonymous = is_anonymous;
 
     // Update stats
     creator_profile.total_donations_received += creator_amount;
@@ -92,28 +117,53 @@ pub struct DonateToCreatorCtx<'info> {
     #[account(mut)]
     pub program_state: Account<'info, ProgramState>,
     
+    #[account(
+        mut,
+        seeds = [
+            b"user_profile",
+            creator.as_ref()
+        ],
+        bump
+    )]
+    pub creator_profile: Account<'info, UserProfile>,
+    
+    // Passed for named donations only
+    #[account(
+        init_if_needed,
+        payer = donor,
+        space = ANCHOR_DISCRIMINATOR_SIZE + DonationSummary::INIT_SPACE,
+        seeds = [
+            b"donation_summary",
+            donor.key().as_ref(),
+            creator.as_ref(),
+        ],
+        bump
+    )]
+    pub donation_summary: Option<Account<'info, DonationSummary>>,
+    
+    // Seeded by the creator's counter so the receipt never names the donor
     #[account(
         init,
         payer = donor,
         space = ANCHOR_DISCRIMINATOR_SIZE + Donation::INIT_SPACE,
         seeds = [
             b"donation",
-            donor.key().as_ref(),
             creator.as_ref(),
+            (creator_profile.donations_received_count + 1).to_le_bytes().as_ref(),
         ],
         bump
     )]
     pub donation: Account<'info, Donation>,
     
+    #[account(mut)]
+    pub creator_wallet: SystemAccount<'info>,
+    
     #[account(
         mut,
-        seeds = [
-            b"user_profile",
-            creator.as_ref()
-        ],
-        bump
+        seeds = [b"treasury"],
+        bump = program_state.treasury_bump
     )]
-    pub creator_profile: Account<'info, UserProfile>,
+    pub treasury: SystemAccount<'info>,
     
     #[account(mut)]
     pub donor: Signer<'info>,
@@ -0,0 +1,249 @@
+use crate::constants::*;
+use crate::errors::ErrorCode::*;
+use crate::states::{
+    ProgramState, UserProfile, Donation, DonationSummary, TokenTipTotal, TreasuryTokenTotal,
+    PauseCategory,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+pub fn donate_token_to_creator(
+    ctx: Context<DonateTokenToCreatorCtx>,
+    creator: Pubkey,
+    amount: u64,
+    message: Option<String>,
+    is_anonymous: bool,
+) -> Result<()> {
+    // Reject while the program or payments are paused
+    ctx.accounts.program_state.require_not_paused(PauseCategory::Payments)?;
+
+    let donation = &mut ctx.accounts.donation;
+    let summary = &mut ctx.accounts.donation_summary;
+    let tip_total = &mut ctx.accounts.token_tip_total;
+    let treasury_total = &mut ctx.accounts.treasury_token_total;
+    let creator_profile = &mut ctx.accounts.creator_profile;
+    let state = &ctx.accounts.program_state;
+    let donor = &ctx.accounts.donor;
+    let mint = &ctx.accounts.mint;
+
+    // Cannot donate to yourself
+    if donor.key() == creator {
+        return Err(CannotDonateToSelf.into());
+    }
+
+    // Token tips have no minimum beyond being non-zero
+    if amount == 0 {
+        return Err(InvalidDonationAmount.into());
+    }
+
+    // Verify creator profile exists
+    if creator_profile.owner != creator {
+        return Err(ProfileNotFound.into());
+    }
+
+    // Verify the destination wallet
+    if ctx.accounts.creator_wallet.key() != creator {
+        return Err(InvalidCreatorWallet.into());
+    }
+
+    // Validate the optional donor message
+    if let Some(ref text) = message {
+        if text.len() > MAX_DONATION_MESSAGE_LENGTH {
+            return Err(DonationMessageTooLong.into());
+        }
+    }
+
+    // Calculate platform fee
+    let platform_fee = state.platform_fee_for(amount)?;
+    let creator_amount = amount - platform_fee;
+
+    // Transfer tokens to creator
+    transfer_checked(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.donor_token_account.to_account_info(),
+                mint: mint.to_account_info(),
+                to: ctx.accounts.creator_token_account.to_account_info(),
+                authority: donor.to_account_info(),
+            },
+        ),
+        creator_amount,
+        mint.decimals,
+    )?;
+
+    // Transfer platform fee into the treasury if applicable
+    if platform_fee > 0 {
+        transfer_checked(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.donor_token_account.to_account_info(),
+                    mint: mint.to_account_info(),
+                    to: ctx.accounts.treasury_token_account.to_account_info(),
+                    authority: donor.to_account_info(),
+                },
+            ),
+            platform_fee,
+            mint.decimals,
+        )?;
+    }
+
+    // Token fees are tracked per mint, separately from the SOL totals
+    treasury_total.mint = mint.key();
+    treasury_total.fees_collected += platform_fee;
+
+    // Anonymous gifts stay out of the donor/creator summary entirely
+    let now = Clock::get()?.unix_timestamp as u64;
+    match (summary, is_anonymous) {
+        (Some(summary), false) => {
+            summary.donor = donor.key();
+            summary.recipient = creator;
+            summary.donation_count += 1;
+            summary.last_donation_at = now;
+        }
+        (None, true) => {}
+        _ => return Err(DonationSummaryMismatch.into()),
+    }
+
+    // Record donation
+    creator_profile.donations_received_count += 1;
+    donation.donation_id = creator_profile.donations_received_count;
+    donation.donor = if is_anonymous { Pubkey::default() } else { donor.key() };
+    donation.recipient = creator;
+    donation.amount = amount;
+    donation.mint = Some(mint.key());
+    donation.post_id = None;
+    donation.timestamp = now;
+    donation.message = message;
+    donation.is_anonymous = is_anonymous;
+
+    // Update per-mint totals for the creator
+    tip_total.creator = creator;
+    tip_total.mint = mint.key();
+    tip_total.total_received += creator_amount;
+    tip_total.tip_count += 1;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(creator: Pubkey)]
+pub struct DonateTokenToCreatorCtx<'info> {
+    #[account(
+        seeds = [b"program_state"],
+        bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"user_profile",
+            creator.as_ref()
+        ],
+        bump
+    )]
+    pub creator_profile: Box<Account<'info, UserProfile>>,
+
+    // Passed for named donations only
+    #[account(
+        init_if_needed,
+        payer = donor,
+        space = ANCHOR_DISCRIMINATOR_SIZE + DonationSummary::INIT_SPACE,
+        seeds = [
+            b"donation_summary",
+            donor.key().as_ref(),
+            creator.as_ref(),
+        ],
+        bump
+    )]
+    pub donation_summary: Option<Box<Account<'info, DonationSummary>>>,
+
+    // Seeded by the creator's counter so the receipt never names the donor
+    #[account(
+        init,
+        payer = donor,
+        space = ANCHOR_DISCRIMINATOR_SIZE + Donation::INIT_SPACE,
+        seeds = [
+            b"donation",
+            creator.as_ref(),
+            (creator_profile.donations_received_count + 1).to_le_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub donation: Box<Account<'info, Donation>>,
+
+    #[account(
+        init_if_needed,
+        payer = donor,
+        space = ANCHOR_DISCRIMINATOR_SIZE + TokenTipTotal::INIT_SPACE,
+        seeds = [
+            b"token_tip_total",
+            creator.as_ref(),
+            mint.key().as_ref(),
+        ],
+        bump
+    )]
+    pub token_tip_total: Box<Account<'info, TokenTipTotal>>,
+
+    #[account(
+        init_if_needed,
+        payer = donor,
+        space = ANCHOR_DISCRIMINATOR_SIZE + TreasuryTokenTotal::INIT_SPACE,
+        seeds = [
+            b"treasury_token_total",
+            mint.key().as_ref(),
+        ],
+        bump
+    )]
+    pub treasury_token_total: Box<Account<'info, TreasuryTokenTotal>>,
+
+    pub creator_wallet: SystemAccount<'info>,
+
+    #[account(
+        seeds = [b"treasury"],
+        bump = program_state.treasury_bump
+    )]
+    pub treasury: SystemAccount<'info>,
+
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = donor,
+        associated_token::token_program = token_program
+    )]
+    pub donor_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = donor,
+        associated_token::mint = mint,
+        associated_token::authority = creator_wallet,
+        associated_token::token_program = token_program
+    )]
+    pub creator_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = donor,
+        associated_token::mint = mint,
+        associated_token::authority = treasury,
+        associated_token::token_program = token_program
+    )]
+    pub treasury_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub donor: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    pub system_program: Program<'info, System>,
+}
\ No newline at end of file
@@ -0,0 +1,60 @@
+use crate::constants::*;
+use crate::errors::ErrorCode::*;
+use crate::states::{Comment, ProgramState, PauseCategory, COMMENT_SEED};
+use anchor_lang::prelude::*;
+
+pub fn edit_comment(
+    ctx: Context<EditCommentCtx>,
+    post_id: u64,
+    comment_id: u64,
+    content: String,
+) -> Result<()> {
+    // Reject while the program or posting is paused
+    ctx.accounts.program_state.require_not_paused(PauseCategory::Posting)?;
+
+    let comment = &mut ctx.accounts.comment;
+
+    // Verify comment exists and matches ID
+    if comment.comment_id != comment_id || comment.post_id != post_id {
+        return Err(CommentNotFound.into());
+    }
+
+    // Only the comment author can edit
+    if comment.author != ctx.accounts.user.key() {
+        return Err(NotCommentAuthor.into());
+    }
+
+    // Validate content length
+    if content.len() > MAX_COMMENT_LENGTH {
+        return Err(CommentTooLong.into());
+    }
+
+    comment.content = content;
+    comment.is_edited = true;
+    comment.edited_at = Clock::get()?.unix_timestamp as u64;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(post_id: u64, comment_id: u64)]
+pub struct EditCommentCtx<'info> {
+    #[account(
+        seeds = [b"program_state"],
+        bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    #[account(
+        mut,
+        seeds = [
+            COMMENT_SEED,
+            post_id.to_le_bytes().as_ref(),
+            comment_id.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub comment: Account<'info, Comment>,
+    
+    pub user: Signer<'info>,
+}
\ No newline at end of file
@@ -0,0 +1,98 @@
+use crate::constants::*;
+use crate::errors::ErrorCode::*;
+use crate::states::{Post, PostRevision, ProgramState, PauseCategory};
+use anchor_lang::prelude::*;
+
+pub fn edit_post(
+    ctx: Context<EditPostCtx>,
+    post_id: u64,
+    content: String,
+    image_url: Option<String>,
+) -> Result<()> {
+    // Reject while the program or posting is paused
+    ctx.accounts.program_state.require_not_paused(PauseCategory::Posting)?;
+
+    let post = &mut ctx.accounts.post;
+    let revision = &mut ctx.accounts.revision;
+    let editor = ctx.accounts.user.key();
+
+    // Verify post exists and matches ID
+    if post.post_id != post_id {
+        return Err(PostNotFound.into());
+    }
+
+    // Verify user is the author or an accepted collaborator
+    if !post.is_owner(&editor) {
+        return Err(CannotEditOthersPost.into());
+    }
+
+    // Check if post is deleted
+    if post.is_deleted {
+        return Err(PostDeleted.into());
+    }
+
+    // Validate content length
+    if content.len() > MAX_POST_CONTENT_LENGTH {
+        return Err(PostContentTooLong.into());
+    }
+
+    // Validate image URL if provided
+    if let Some(ref url) = image_url {
+        if url.len() > MAX_IMAGE_URL_LENGTH {
+            return Err(ImageUrlTooLong.into());
+        }
+    }
+
+    let now = Clock::get()?.unix_timestamp as u64;
+
+    // Keep the version being replaced
+    post.edit_count += 1;
+    revision.post_id = post_id;
+    revision.revision = post.edit_count;
+    revision.editor = editor;
+    revision.content = std::mem::replace(&mut post.content, content);
+    revision.image_url = std::mem::replace(&mut post.image_url, image_url);
+    revision.replaced_at = now;
+
+    post.updated_at = now;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(post_id: u64)]
+pub struct EditPostCtx<'info> {
+    #[account(
+        seeds = [b"program_state"],
+        bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    #[account(
+        mut,
+        seeds = [
+            b"post",
+            post_id.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub post: Account<'info, Post>,
+    
+    #[account(
+        init,
+        payer = user,
+        space = ANCHOR_DISCRIMINATOR_SIZE + PostRevision::INIT_SPACE,
+        seeds = [
+            b"post_revision",
+            post_id.to_le_bytes().as_ref(),
+            (post.edit_count + 1).to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub revision: Account<'info, PostRevision>,
+    
+    #[account(mut)]
+    pub user: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
\ No newline at end of file
@@ -1,9 +1,12 @@
 use crate::constants::ANCHOR_DISCRIMINATOR_SIZE;
 use crate::errors::ErrorCode::*;
-use crate::states::{Follow, UserProfile};
+use crate::states::{Follow, UserProfile, ProgramState, PauseCategory};
 use anchor_lang::prelude::*;
 
 pub fn follow_user(ctx: Context<FollowUserCtx>, target_user: Pubkey) -> Result<()> {
+    // Reject while the program or social features are paused
+    ctx.accounts.program_state.require_not_paused(PauseCategory::Social)?;
+
     let follow = &mut ctx.accounts.follow;
     let follower_profile = &mut ctx.accounts.follower_profile;
     let following_profile = &mut ctx.accounts.following_profile;
@@ -33,6 +36,12 @@ pub fn follow_user(ctx: Context<FollowUserCtx>, target_user: Pubkey) -> Result<(
 #[derive(Accounts)]
 #[instruction(target_user: Pubkey)]
 pub struct FollowUserCtx<'info> {
+    #[account(
+        seeds = [b"program_state"],
+        bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
     #[account(
         init,
         payer = follower,
@@ -1,5 +1,6 @@
 use anchor_lang::prelude::*;
-use crate::constants::ANCHOR_DISCRIMINATOR_SIZE;
+use anchor_lang::system_program::{transfer, Transfer};
+use crate::constants::{ANCHOR_DISCRIMINATOR_SIZE, DEFAULT_PLATFORM_FEE_BPS};
 use crate::errors::ErrorCode::AlreadyInitialized;
 use crate::states::ProgramState;
 
@@ -14,11 +15,36 @@ pub fn initialize(ctx: Context<InitializeCtx>) -> Result<()> {
     state.user_count = 0;
     state.post_count = 0;
     state.comment_count = 0;
-    state.platform_fee = 5; // 5% platform fee
+    state.platform_fee = DEFAULT_PLATFORM_FEE_BPS;
     state.platform_address = deployer.key();
+    state.admin = deployer.key();
+    state.pending_admin = None;
     state.total_donations = 0;
+    state.treasury_bump = ctx.bumps.treasury;
+    state.total_fees_collected = 0;
+    state.total_fees_withdrawn = 0;
+    state.paused = false;
+    state.posting_paused = false;
+    state.social_paused = false;
+    state.payments_paused = false;
     state.initialized = true;
 
+    // Fund the treasury up to rent exemption so small fees can be paid into it
+    let rent_exempt_minimum = Rent::get()?.minimum_balance(0);
+    let treasury_balance = ctx.accounts.treasury.lamports();
+    if treasury_balance < rent_exempt_minimum {
+        transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: deployer.to_account_info(),
+                    to: ctx.accounts.treasury.to_account_info(),
+                },
+            ),
+            rent_exempt_minimum - treasury_balance,
+        )?;
+    }
+
     Ok(())
 }
 
@@ -33,6 +59,13 @@ pub struct InitializeCtx<'info> {
     )]
     pub program_state: Account<'info, ProgramState>,
     
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump
+    )]
+    pub treasury: SystemAccount<'info>,
+    
     #[account(mut)]
     pub deployer: Signer<'info>,
     
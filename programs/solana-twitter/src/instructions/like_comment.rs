@@ -0,0 +1,72 @@
+use crate::constants::ANCHOR_DISCRIMINATOR_SIZE;
+use crate::errors::ErrorCode::*;
+use crate::states::{CommentLike, Comment, ProgramState, PauseCategory, COMMENT_SEED};
+use anchor_lang::prelude::*;
+
+pub fn like_comment(
+    ctx: Context<LikeCommentCtx>,
+    post_id: u64,
+    comment_id: u64,
+) -> Result<()> {
+    // Reject while the program or social features are paused
+    ctx.accounts.program_state.require_not_paused(PauseCategory::Social)?;
+
+    let like = &mut ctx.accounts.comment_like;
+    let comment = &mut ctx.accounts.comment;
+
+    // Verify comment exists and matches ID
+    if comment.comment_id != comment_id || comment.post_id != post_id {
+        return Err(CommentNotFound.into());
+    }
+
+    // Initialize like
+    like.user = ctx.accounts.user.key();
+    like.post_id = post_id;
+    like.comment_id = comment_id;
+    like.created_at = Clock::get()?.unix_timestamp as u64;
+
+    // Increment like count
+    comment.likes_count += 1;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(post_id: u64, comment_id: u64)]
+pub struct LikeCommentCtx<'info> {
+    #[account(
+        seeds = [b"program_state"],
+        bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    #[account(
+        init,
+        payer = user,
+        space = ANCHOR_DISCRIMINATOR_SIZE + CommentLike::INIT_SPACE,
+        seeds = [
+            b"comment_like",
+            user.key().as_ref(),
+            post_id.to_le_bytes().as_ref(),
+            comment_id.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub comment_like: Account<'info, CommentLike>,
+    
+    #[account(
+        mut,
+        seeds = [
+            COMMENT_SEED,
+            post_id.to_le_bytes().as_ref(),
+            comment_id.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub comment: Account<'info, Comment>,
+    
+    #[account(mut)]
+    pub user: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
\ No newline at end of file
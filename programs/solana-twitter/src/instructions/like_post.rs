@@ -1,9 +1,12 @@
 use crate::constants::ANCHOR_DISCRIMINATOR_SIZE;
 use crate::errors::ErrorCode::*;
-use crate::states::{Like, Post};
+use crate::states::{Like, Post, ProgramState, PauseCategory};
 use anchor_lang::prelude::*;
 
 pub fn like_post(ctx: Context<LikePostCtx>, post_id: u64) -> Result<()> {
+    // Reject while the program or social features are paused
+    ctx.accounts.program_state.require_not_paused(PauseCategory::Social)?;
+
     let like = &mut ctx.accounts.like;
     let post = &mut ctx.accounts.post;
 
@@ -31,6 +34,12 @@ pub fn like_post(ctx: Context<LikePostCtx>, post_id: u64) -> Result<()> {
 #[derive(Accounts)]
 #[instruction(post_id: u64)]
 pub struct LikePostCtx<'info> {
+    #[account(
+        seeds = [b"program_state"],
+        bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
     #[account(
         init,
         payer = user,
@@ -1,27 +1,75 @@
 pub mod initialize;
 pub mod create_profile;
 pub mod update_profile;
+pub mod change_username;
 pub mod create_post;
+pub mod create_reply;
+pub mod quote_post;
 pub mod delete_post;
+pub mod purge_post;
+pub mod edit_post;
 pub mod follow_user;
 pub mod unfollow_user;
 pub mod like_post;
 pub mod unlike_post;
+pub mod repost;
+pub mod undo_repost;
+pub mod react_to_post;
+pub mod remove_reaction;
 pub mod create_comment;
 pub mod delete_comment;
+pub mod edit_comment;
+pub mod set_comment_hidden;
+pub mod like_comment;
+pub mod unlike_comment;
 pub mod donate_to_creator;
+pub mod donate_token_to_creator;
+pub mod tip_post;
 pub mod create_collaboration_post;
+pub mod accept_collaboration;
+pub mod reject_collaboration;
+pub mod update_platform_fee;
+pub mod update_platform_address;
+pub mod propose_admin;
+pub mod accept_admin;
+pub mod set_paused;
+pub mod withdraw_treasury;
+pub mod withdraw_treasury_token;
 
 pub use initialize::*;
 pub use create_profile::*;
 pub use update_profile::*;
+pub use change_username::*;
 pub use create_post::*;
+pub use create_reply::*;
+pub use quote_post::*;
 pub use delete_post::*;
+pub use purge_post::*;
+pub use edit_post::*;
 pub use follow_user::*;
 pub use unfollow_user::*;
 pub use like_post::*;
 pub use unlike_post::*;
+pub use repost::*;
+pub use undo_repost::*;
+pub use react_to_post::*;
+pub use remove_reaction::*;
 pub use create_comment::*;
 pub use delete_comment::*;
+pub use edit_comment::*;
+pub use set_comment_hidden::*;
+pub use like_comment::*;
+pub use unlike_comment::*;
 pub use donate_to_creator::*;
-pub use create_collaboration_post::*;
\ No newline at end of file
+pub use donate_token_to_creator::*;
+pub use tip_post::*;
+pub use create_collaboration_post::*;
+pub use accept_collaboration::*;
+pub use reject_collaboration::*;
+pub use update_platform_fee::*;
+pub use update_platform_address::*;
+pub use propose_admin::*;
+pub use accept_admin::*;
+pub use set_paused::*;
+pub use withdraw_treasury::*;
+pub use withdraw_treasury_token::*;
\ No newline at end of file
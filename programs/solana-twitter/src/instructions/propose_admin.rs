@@ -0,0 +1,29 @@
+use crate::errors::ErrorCode::*;
+use crate::states::ProgramState;
+use anchor_lang::prelude::*;
+
+pub fn propose_admin(ctx: Context<ProposeAdminCtx>, new_admin: Pubkey) -> Result<()> {
+    let state = &mut ctx.accounts.program_state;
+
+    // Only the current admin can nominate a successor
+    if state.admin != ctx.accounts.admin.key() {
+        return Err(UnauthorizedAdmin.into());
+    }
+
+    // The nominee must call accept_admin before the handover takes effect
+    state.pending_admin = Some(new_admin);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ProposeAdminCtx<'info> {
+    #[account(
+        mut,
+        seeds = [b"program_state"],
+        bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    pub admin: Signer<'info>,
+}
\ No newline at end of file
@@ -0,0 +1,153 @@
+use crate::constants::ANCHOR_DISCRIMINATOR_SIZE;
+use crate::errors::ErrorCode::*;
+use crate::states::{Post, PostRevision, PostTombstone, UserProfile, ProgramState, PauseCategory};
+use crate::instructions::delete_post::{release_collaborator_posts, release_related_post};
+use anchor_lang::prelude::*;
+
+// Remaining accounts: the UserProfile of every accepted collaborator in
+// post.collaborators order, then a (revision, editor) pair for every revision
+// 1..=post.edit_count, so the old content is closed along with the post
+pub fn purge_post<'info>(
+    ctx: Context<'_, '_, 'info, 'info, PurgePostCtx<'info>>,
+    post_id: u64,
+) -> Result<()> {
+    // Reject while the program or posting is paused
+    ctx.accounts.program_state.require_not_paused(PauseCategory::Posting)?;
+
+    let post = &ctx.accounts.post;
+    let tombstone = &mut ctx.accounts.tombstone;
+    let user_profile = &mut ctx.accounts.user_profile;
+
+    // Verify post exists and matches ID
+    if post.post_id != post_id {
+        return Err(PostNotFound.into());
+    }
+
+    // Only the original author paid for the account and gets the rent back
+    if post.author != ctx.accounts.user.key() {
+        return Err(CannotDeleteOthersPost.into());
+    }
+
+    // Comments are seeded under the post and the author can delete them, so
+    // they go first. Likes, reactions and reposts stay behind and are undone
+    // against the tombstone once the post is gone
+    if post.comments_count > 0 {
+        return Err(PostHasComments.into());
+    }
+
+    let collaborator_count = post.accepted_collaborators().count();
+    if ctx.remaining_accounts.len() < collaborator_count {
+        return Err(CollaboratorAccountsMismatch.into());
+    }
+    let (profiles, revisions) = ctx.remaining_accounts.split_at(collaborator_count);
+
+    // Close every revision so no earlier version of the content survives;
+    // each one refunds the editor who paid for it
+    if revisions.len() as u64 != post.edit_count * 2 {
+        return Err(RevisionAccountsMismatch.into());
+    }
+    for (number, pair) in (1..=post.edit_count).zip(revisions.chunks(2)) {
+        let revision = Account::<PostRevision>::try_from(&pair[0])?;
+        let editor = &pair[1];
+
+        if revision.post_id != post_id
+            || revision.revision != number
+            || editor.key() != revision.editor
+        {
+            return Err(RevisionAccountsMismatch.into());
+        }
+
+        revision.close(editor.clone())?;
+    }
+
+    // Soft-deleted posts were already taken off every count
+    if !post.is_deleted {
+        user_profile.posts_count = user_profile.posts_count.saturating_sub(1);
+        release_collaborator_posts(post, profiles)?;
+        release_related_post(post.reply_to, ctx.accounts.parent_post.as_ref(), |p| {
+            &mut p.replies_count
+        })?;
+        release_related_post(post.quote_of, ctx.accounts.quoted_post.as_ref(), |p| {
+            &mut p.quotes_count
+        })?;
+    }
+
+    // Record the deletion; the post account is closed back to the author
+    tombstone.post_id = post_id;
+    tombstone.author = post.author;
+    tombstone.deleted_at = Clock::get()?.unix_timestamp as u64;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(post_id: u64)]
+pub struct PurgePostCtx<'info> {
+    #[account(
+        seeds = [b"program_state"],
+        bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    #[account(
+        mut,
+        close = user,
+        seeds = [
+            b"post",
+            post_id.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub post: Account<'info, Post>,
+    
+    /// CHECK: the post this one replies to, derived from post.reply_to; it
+    /// may already be purged
+    #[account(
+        mut,
+        seeds = [
+            b"post",
+            post.reply_to.unwrap_or_default().to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub parent_post: Option<UncheckedAccount<'info>>,
+    
+    /// CHECK: the post this one quotes, derived from post.quote_of; it may
+    /// already be purged
+    #[account(
+        mut,
+        seeds = [
+            b"post",
+            post.quote_of.unwrap_or_default().to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub quoted_post: Option<UncheckedAccount<'info>>,
+    
+    #[account(
+        init,
+        payer = user,
+        space = ANCHOR_DISCRIMINATOR_SIZE + PostTombstone::INIT_SPACE,
+        seeds = [
+            b"post_tombstone",
+            post_id.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub tombstone: Account<'info, PostTombstone>,
+    
+    #[account(
+        mut,
+        seeds = [
+            b"user_profile",
+            user.key().as_ref()
+        ],
+        bump
+    )]
+    pub user_profile: Account<'info, UserProfile>,
+    
+    #[account(mut)]
+    pub user: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
\ No newline at end of file
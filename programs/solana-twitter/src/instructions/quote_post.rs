@@ -0,0 +1,102 @@
+use crate::constants::*;
+use crate::errors::ErrorCode::*;
+use crate::states::{ProgramState, UserProfile, Post, PauseCategory};
+use anchor_lang::prelude::*;
+
+pub fn quote_post(
+    ctx: Context<QuotePostCtx>,
+    quoted_post_id: u64,
+    content: String,
+    image_url: Option<String>,
+) -> Result<()> {
+    // Reject while the program or posting is paused
+    ctx.accounts.program_state.require_not_paused(PauseCategory::Posting)?;
+
+    let post = &mut ctx.accounts.post;
+    let quoted = &mut ctx.accounts.quoted_post;
+    let state = &mut ctx.accounts.program_state;
+    let user_profile = &mut ctx.accounts.user_profile;
+
+    // Verify quoted post exists and matches ID
+    if quoted.post_id != quoted_post_id {
+        return Err(PostNotFound.into());
+    }
+
+    // Cannot quote a deleted post
+    if quoted.is_deleted {
+        return Err(PostDeleted.into());
+    }
+
+    // Validate content length
+    if content.len() > MAX_POST_CONTENT_LENGTH {
+        return Err(PostContentTooLong.into());
+    }
+
+    // Validate image URL if provided
+    if let Some(ref url) = image_url {
+        if url.len() > MAX_IMAGE_URL_LENGTH {
+            return Err(ImageUrlTooLong.into());
+        }
+    }
+
+    // Increment counters
+    state.post_count += 1;
+    user_profile.posts_count += 1;
+    quoted.quotes_count += 1;
+
+    // Initialize quote post
+    post.initialize(
+        state.post_count,
+        ctx.accounts.user.key(),
+        content,
+        image_url,
+        Clock::get()?.unix_timestamp as u64,
+    );
+    post.quote_of = Some(quoted_post_id);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(quoted_post_id: u64)]
+pub struct QuotePostCtx<'info> {
+    #[account(mut)]
+    pub program_state: Account<'info, ProgramState>,
+    
+    #[account(
+        mut,
+        seeds = [
+            b"user_profile",
+            user.key().as_ref()
+        ],
+        bump
+    )]
+    pub user_profile: Account<'info, UserProfile>,
+    
+    #[account(
+        mut,
+        seeds = [
+            b"post",
+            quoted_post_id.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub quoted_post: Account<'info, Post>,
+    
+    #[account(
+        init,
+        payer = user,
+        space = ANCHOR_DISCRIMINATOR_SIZE + Post::INIT_SPACE,
+        seeds = [
+            b"post",
+            (program_state.post_count + 1).to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub post: Account<'info, Post>,
+    
+    #[account(mut)]
+    pub user: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
\ No newline at end of file
@@ -0,0 +1,84 @@
+use crate::constants::ANCHOR_DISCRIMINATOR_SIZE;
+use crate::errors::ErrorCode::*;
+use crate::states::{Reaction, ReactionKind, Post, ProgramState, PauseCategory};
+use anchor_lang::prelude::*;
+
+pub fn react_to_post(
+    ctx: Context<ReactToPostCtx>,
+    post_id: u64,
+    kind: ReactionKind,
+) -> Result<()> {
+    // Reject while the program or social features are paused
+    ctx.accounts.program_state.require_not_paused(PauseCategory::Social)?;
+
+    let reaction = &mut ctx.accounts.reaction;
+    let post = &mut ctx.accounts.post;
+
+    // Verify post exists and matches ID
+    if post.post_id != post_id {
+        return Err(PostNotFound.into());
+    }
+
+    // Check if post is deleted
+    if post.is_deleted {
+        return Err(PostDeleted.into());
+    }
+
+    // An existing reaction is switched to the new kind
+    if reaction.user != Pubkey::default() {
+        if reaction.kind == kind {
+            return Err(AlreadyReacted.into());
+        }
+        let previous = reaction.kind.index();
+        post.reaction_counts[previous] = post.reaction_counts[previous].saturating_sub(1);
+    } else {
+        reaction.user = ctx.accounts.user.key();
+        reaction.post_id = post_id;
+    }
+
+    reaction.kind = kind;
+    reaction.created_at = Clock::get()?.unix_timestamp as u64;
+
+    // Increment the counter for the new kind
+    post.reaction_counts[kind.index()] += 1;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(post_id: u64)]
+pub struct ReactToPostCtx<'info> {
+    #[account(
+        seeds = [b"program_state"],
+        bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = ANCHOR_DISCRIMINATOR_SIZE + Reaction::INIT_SPACE,
+        seeds = [
+            b"reaction",
+            user.key().as_ref(),
+            post_id.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub reaction: Account<'info, Reaction>,
+    
+    #[account(
+        mut,
+        seeds = [
+            b"post",
+            post_id.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub post: Account<'info, Post>,
+    
+    #[account(mut)]
+    pub user: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
\ No newline at end of file
@@ -0,0 +1,53 @@
+use crate::errors::ErrorCode::*;
+use crate::states::{Post, ProgramState, CollaborationStatus, PauseCategory};
+use anchor_lang::prelude::*;
+
+pub fn reject_collaboration(ctx: Context<RejectCollaborationCtx>, post_id: u64) -> Result<()> {
+    // Reject while the program or posting is paused
+    ctx.accounts.program_state.require_not_paused(PauseCategory::Posting)?;
+
+    let post = &mut ctx.accounts.post;
+
+    // Verify post exists and matches ID
+    if post.post_id != post_id {
+        return Err(PostNotFound.into());
+    }
+
+    // Only an invited collaborator can decline, and only their own invite
+    let Some(entry) = post.collaborator_mut(&ctx.accounts.collaborator.key()) else {
+        return Err(NotInvitedCollaborator.into());
+    };
+
+    // Invite must still be open
+    if entry.status != CollaborationStatus::Pending {
+        return Err(CollaborationNotPending.into());
+    }
+
+    // Other co-authors are unaffected; with no acceptances the post stays
+    // up as a regular post of its author
+    entry.status = CollaborationStatus::Rejected;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(post_id: u64)]
+pub struct RejectCollaborationCtx<'info> {
+    #[account(
+        seeds = [b"program_state"],
+        bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    #[account(
+        mut,
+        seeds = [
+            b"post",
+            post_id.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub post: Account<'info, Post>,
+    
+    pub collaborator: Signer<'info>,
+}
\ No newline at end of file
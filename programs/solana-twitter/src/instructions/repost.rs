@@ -0,0 +1,70 @@
+use crate::constants::ANCHOR_DISCRIMINATOR_SIZE;
+use crate::errors::ErrorCode::*;
+use crate::states::{Repost, Post, ProgramState, PauseCategory};
+use anchor_lang::prelude::*;
+
+pub fn repost(ctx: Context<RepostCtx>, post_id: u64) -> Result<()> {
+    // Reject while the program or posting is paused
+    ctx.accounts.program_state.require_not_paused(PauseCategory::Posting)?;
+
+    let repost = &mut ctx.accounts.repost;
+    let post = &mut ctx.accounts.post;
+
+    // Verify post exists and matches ID
+    if post.post_id != post_id {
+        return Err(PostNotFound.into());
+    }
+
+    // Check if post is deleted
+    if post.is_deleted {
+        return Err(PostDeleted.into());
+    }
+
+    // Initialize repost
+    repost.user = ctx.accounts.user.key();
+    repost.post_id = post_id;
+    repost.created_at = Clock::get()?.unix_timestamp as u64;
+
+    // Increment repost count
+    post.reposts_count += 1;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(post_id: u64)]
+pub struct RepostCtx<'info> {
+    #[account(
+        seeds = [b"program_state"],
+        bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    #[account(
+        init,
+        payer = user,
+        space = ANCHOR_DISCRIMINATOR_SIZE + Repost::INIT_SPACE,
+        seeds = [
+            b"repost",
+            user.key().as_ref(),
+            post_id.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub repost: Account<'info, Repost>,
+    
+    #[account(
+        mut,
+        seeds = [
+            b"post",
+            post_id.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub post: Account<'info, Post>,
+    
+    #[account(mut)]
+    pub user: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
\ No newline at end of file
@@ -0,0 +1,62 @@
+use crate::errors::ErrorCode::*;
+use crate::states::{Comment, Post, ProgramState, PauseCategory, COMMENT_SEED};
+use anchor_lang::prelude::*;
+
+pub fn set_comment_hidden(
+    ctx: Context<SetCommentHiddenCtx>,
+    post_id: u64,
+    comment_id: u64,
+    hidden: bool,
+) -> Result<()> {
+    // Reject while the program or posting is paused
+    ctx.accounts.program_state.require_not_paused(PauseCategory::Posting)?;
+
+    let comment = &mut ctx.accounts.comment;
+    let post = &ctx.accounts.post;
+
+    // Verify comment exists and matches ID
+    if comment.comment_id != comment_id || comment.post_id != post_id {
+        return Err(CommentNotFound.into());
+    }
+
+    // Only the post author or collaborator can moderate its comments
+    if !post.is_owner(&ctx.accounts.user.key()) {
+        return Err(NotPostOwner.into());
+    }
+
+    comment.is_hidden = hidden;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(post_id: u64, comment_id: u64)]
+pub struct SetCommentHiddenCtx<'info> {
+    #[account(
+        seeds = [b"program_state"],
+        bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    #[account(
+        mut,
+        seeds = [
+            COMMENT_SEED,
+            post_id.to_le_bytes().as_ref(),
+            comment_id.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub comment: Account<'info, Comment>,
+    
+    #[account(
+        seeds = [
+            b"post",
+            post_id.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub post: Account<'info, Post>,
+    
+    pub user: Signer<'info>,
+}
\ No newline at end of file
@@ -0,0 +1,46 @@
+use crate::errors::ErrorCode::*;
+use crate::states::ProgramState;
+use anchor_lang::prelude::*;
+
+pub fn set_paused(
+    ctx: Context<SetPausedCtx>,
+    paused: Option<bool>,
+    posting_paused: Option<bool>,
+    social_paused: Option<bool>,
+    payments_paused: Option<bool>,
+) -> Result<()> {
+    let state = &mut ctx.accounts.program_state;
+
+    // Only the admin can freeze or unfreeze the program
+    if state.admin != ctx.accounts.admin.key() {
+        return Err(UnauthorizedAdmin.into());
+    }
+
+    // Update each flag only if provided
+    if let Some(flag) = paused {
+        state.paused = flag;
+    }
+    if let Some(flag) = posting_paused {
+        state.posting_paused = flag;
+    }
+    if let Some(flag) = social_paused {
+        state.social_paused = flag;
+    }
+    if let Some(flag) = payments_paused {
+        state.payments_paused = flag;
+    }
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetPausedCtx<'info> {
+    #[account(
+        mut,
+        seeds = [b"program_state"],
+        bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    pub admin: Signer<'info>,
+}
\ No newline at end of file
@@ -0,0 +1,232 @@
+use crate::constants::*;
+use crate::errors::ErrorCode::*;
+use crate::states::{ProgramState, UserProfile, Post, Donation, DonationSummary, PauseCategory};
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+
+// Remaining accounts: a (wallet, profile) pair for every accepted
+// collaborator, in the order they appear in post.collaborators
+pub fn tip_post<'info>(
+    ctx: Context<'_, '_, 'info, 'info, TipPostCtx<'info>>,
+    post_id: u64,
+    amount: u64,
+    message: Option<String>,
+    is_anonymous: bool,
+) -> Result<()> {
+    // Reject while the program or payments are paused
+    ctx.accounts.program_state.require_not_paused(PauseCategory::Payments)?;
+
+    let post = &mut ctx.accounts.post;
+    let donation = &mut ctx.accounts.donation;
+    let summary = &mut ctx.accounts.donation_summary;
+    let state = &mut ctx.accounts.program_state;
+    let author_profile = &mut ctx.accounts.author_profile;
+    let donor = &ctx.accounts.donor;
+
+    // Verify post exists and matches ID
+    if post.post_id != post_id {
+        return Err(PostNotFound.into());
+    }
+
+    // Cannot tip a deleted post
+    if post.is_deleted {
+        return Err(PostDeleted.into());
+    }
+
+    // Authors and collaborators cannot tip their own post
+    if post.is_owner(&donor.key()) {
+        return Err(CannotDonateToSelf.into());
+    }
+
+    // Validate minimum donation amount (0.02 SOL)
+    if amount < MIN_DONATION_AMOUNT {
+        return Err(InvalidDonationAmount.into());
+    }
+
+    // Validate the optional donor message
+    if let Some(ref text) = message {
+        if text.len() > MAX_DONATION_MESSAGE_LENGTH {
+            return Err(DonationMessageTooLong.into());
+        }
+    }
+
+    // Calculate platform fee; the rest is split between the post's owners
+    let platform_fee = state.platform_fee_for(amount)?;
+    let net_amount = amount - platform_fee;
+
+    // Pending and rejected collaborators' shares stay with the author
+    let accepted: Vec<_> = post.accepted_collaborators().collect();
+
+    if ctx.remaining_accounts.len() != accepted.len() * 2 {
+        return Err(CollaboratorAccountsMismatch.into());
+    }
+
+    // Pay each accepted collaborator their share
+    let mut collaborators_total: u64 = 0;
+    for (collaborator, pair) in accepted.iter().zip(ctx.remaining_accounts.chunks(2)) {
+        let wallet = &pair[0];
+        let mut profile = Account::<UserProfile>::try_from(&pair[1])?;
+
+        if wallet.key() != collaborator.key || profile.owner != collaborator.key {
+            return Err(CollaboratorAccountsMismatch.into());
+        }
+
+        let share = (net_amount as u128 * collaborator.share_bps as u128
+            / FEE_BASIS_POINTS as u128) as u64;
+
+        if share > 0 {
+            transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    Transfer {
+                        from: donor.to_account_info(),
+                        to: wallet.clone(),
+                    },
+                ),
+                share,
+            )?;
+        }
+
+        profile.total_donations_received += share;
+        profile.exit(&crate::ID)?;
+        collaborators_total += share;
+    }
+
+    // The author receives the remainder, including any rounding dust
+    let author_amount = net_amount - collaborators_total;
+    transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: donor.to_account_info(),
+                to: ctx.accounts.author_wallet.to_account_info(),
+            },
+        ),
+        author_amount,
+    )?;
+
+    // Transfer platform fee into the treasury if applicable
+    if platform_fee > 0 {
+        transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: donor.to_account_info(),
+                    to: ctx.accounts.treasury.to_account_info(),
+                },
+            ),
+            platform_fee,
+        )?;
+        state.total_fees_collected += platform_fee;
+    }
+
+    // Anonymous gifts stay out of the donor/creator summary entirely
+    let now = Clock::get()?.unix_timestamp as u64;
+    match (summary, is_anonymous) {
+        (Some(summary), false) => {
+            summary.donor = donor.key();
+            summary.recipient = post.author;
+            summary.donation_count += 1;
+            summary.total_amount += amount;
+            summary.last_donation_at = now;
+        }
+        (None, true) => {}
+        _ => return Err(DonationSummaryMismatch.into()),
+    }
+
+    // Record the tip against the post that earned it
+    author_profile.donations_received_count += 1;
+    donation.donation_id = author_profile.donations_received_count;
+    donation.donor = if is_anonymous { Pubkey::default() } else { donor.key() };
+    donation.recipient = post.author;
+    donation.amount = amount;
+    donation.mint = None;
+    donation.post_id = Some(post_id);
+    donation.timestamp = now;
+    donation.message = message;
+    donation.is_anonymous = is_anonymous;
+
+    // Update stats
+    post.tips_total += amount;
+    author_profile.total_donations_received += author_amount;
+    state.total_donations += amount;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(post_id: u64)]
+pub struct TipPostCtx<'info> {
+    #[account(
+        mut,
+        seeds = [b"program_state"],
+        bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    #[account(
+        mut,
+        seeds = [
+            b"post",
+            post_id.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub post: Account<'info, Post>,
+    
+    #[account(
+        mut,
+        seeds = [
+            b"user_profile",
+            post.author.as_ref()
+        ],
+        bump
+    )]
+    pub author_profile: Account<'info, UserProfile>,
+    
+    // Passed for named donations only
+    #[account(
+        init_if_needed,
+        payer = donor,
+        space = ANCHOR_DISCRIMINATOR_SIZE + DonationSummary::INIT_SPACE,
+        seeds = [
+            b"donation_summary",
+            donor.key().as_ref(),
+            post.author.as_ref(),
+        ],
+        bump
+    )]
+    pub donation_summary: Option<Box<Account<'info, DonationSummary>>>,
+    
+    // Seeded by the creator's counter so the receipt never names the donor
+    #[account(
+        init,
+        payer = donor,
+        space = ANCHOR_DISCRIMINATOR_SIZE + Donation::INIT_SPACE,
+        seeds = [
+            b"donation",
+            post.author.as_ref(),
+            (author_profile.donations_received_count + 1).to_le_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub donation: Box<Account<'info, Donation>>,
+    
+    #[account(
+        mut,
+        address = post.author @ InvalidCreatorWallet
+    )]
+    pub author_wallet: SystemAccount<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump = program_state.treasury_bump
+    )]
+    pub treasury: SystemAccount<'info>,
+    
+    #[account(mut)]
+    pub donor: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
\ No newline at end of file
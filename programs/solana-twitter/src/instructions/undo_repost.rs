@@ -0,0 +1,77 @@
+use crate::errors::ErrorCode::*;
+use crate::states::{Repost, Post, ProgramState, PauseCategory};
+use anchor_lang::prelude::*;
+
+pub fn undo_repost(ctx: Context<UndoRepostCtx>, post_id: u64) -> Result<()> {
+    // Reject while the program or posting is paused
+    ctx.accounts.program_state.require_not_paused(PauseCategory::Posting)?;
+
+    let repost = &ctx.accounts.repost;
+    let post_info = ctx.accounts.post.to_account_info();
+
+    // Verify repost exists and matches user and post
+    if repost.user != ctx.accounts.user.key() || repost.post_id != post_id {
+        return Err(NotReposted.into());
+    }
+
+    // A purged post's account is already closed; the repost is still
+    // closed so its rent comes back, there is just no counter to update
+    if post_info.data_is_empty() {
+        return Ok(());
+    }
+
+    if *post_info.owner != crate::ID {
+        return Err(PostNotFound.into());
+    }
+
+    let mut data = post_info.try_borrow_mut_data()?;
+    let mut post = Post::try_deserialize(&mut &data[..])?;
+
+    // Verify post exists
+    if post.post_id != post_id {
+        return Err(PostNotFound.into());
+    }
+
+    // Decrement repost count
+    post.reposts_count = post.reposts_count.saturating_sub(1);
+    post.try_serialize(&mut &mut data[..])?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(post_id: u64)]
+pub struct UndoRepostCtx<'info> {
+    #[account(
+        seeds = [b"program_state"],
+        bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    #[account(
+        mut,
+        close = user,
+        seeds = [
+            b"repost",
+            user.key().as_ref(),
+            post_id.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub repost: Account<'info, Repost>,
+    
+    /// CHECK: the post PDA, which may already be closed by purge_post;
+    /// deserialized in the handler only when it still exists
+    #[account(
+        mut,
+        seeds = [
+            b"post",
+            post_id.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub post: UncheckedAccount<'info>,
+    
+    #[account(mut)]
+    pub user: Signer<'info>,
+}
\ No newline at end of file
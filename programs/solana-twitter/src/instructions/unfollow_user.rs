@@ -1,8 +1,11 @@
 use crate::errors::ErrorCode::*;
-use crate::states::{Follow, UserProfile};
+use crate::states::{Follow, UserProfile, ProgramState, PauseCategory};
 use anchor_lang::prelude::*;
 
 pub fn unfollow_user(ctx: Context<UnfollowUserCtx>, target_user: Pubkey) -> Result<()> {
+    // Reject while the program or social features are paused
+    ctx.accounts.program_state.require_not_paused(PauseCategory::Social)?;
+
     let follow = &ctx.accounts.follow;
     let follower_profile = &mut ctx.accounts.follower_profile;
     let following_profile = &mut ctx.accounts.following_profile;
@@ -22,6 +25,12 @@ pub fn unfollow_user(ctx: Context<UnfollowUserCtx>, target_user: Pubkey) -> Resu
 #[derive(Accounts)]
 #[instruction(target_user: Pubkey)]
 pub struct UnfollowUserCtx<'info> {
+    #[account(
+        seeds = [b"program_state"],
+        bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
     #[account(
         mut,
         close = follower,
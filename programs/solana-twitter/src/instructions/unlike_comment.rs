@@ -0,0 +1,86 @@
+use crate::errors::ErrorCode::*;
+use crate::states::{CommentLike, Comment, ProgramState, PauseCategory, COMMENT_SEED};
+use anchor_lang::prelude::*;
+
+pub fn unlike_comment(
+    ctx: Context<UnlikeCommentCtx>,
+    post_id: u64,
+    comment_id: u64,
+) -> Result<()> {
+    // Reject while the program or social features are paused
+    ctx.accounts.program_state.require_not_paused(PauseCategory::Social)?;
+
+    let like = &ctx.accounts.comment_like;
+    let comment_info = ctx.accounts.comment.to_account_info();
+
+    // Verify like exists and matches user and comment
+    if like.user != ctx.accounts.user.key()
+        || like.post_id != post_id
+        || like.comment_id != comment_id
+    {
+        return Err(NotLiked.into());
+    }
+
+    // A deleted comment's account is already closed; the like is still
+    // closed so its rent comes back, there is just no counter to update
+    if comment_info.data_is_empty() {
+        return Ok(());
+    }
+
+    if *comment_info.owner != crate::ID {
+        return Err(CommentNotFound.into());
+    }
+
+    let mut data = comment_info.try_borrow_mut_data()?;
+    let mut comment = Comment::try_deserialize(&mut &data[..])?;
+
+    // Verify comment matches
+    if comment.comment_id != comment_id || comment.post_id != post_id {
+        return Err(CommentNotFound.into());
+    }
+
+    // Decrement like count
+    comment.likes_count = comment.likes_count.saturating_sub(1);
+    comment.try_serialize(&mut &mut data[..])?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(post_id: u64, comment_id: u64)]
+pub struct UnlikeCommentCtx<'info> {
+    #[account(
+        seeds = [b"program_state"],
+        bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    #[account(
+        mut,
+        close = user,
+        seeds = [
+            b"comment_like",
+            user.key().as_ref(),
+            post_id.to_le_bytes().as_ref(),
+            comment_id.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub comment_like: Account<'info, CommentLike>,
+    
+    /// CHECK: the comment PDA, which may already be closed by delete_comment;
+    /// deserialized in the handler only when it still exists
+    #[account(
+        mut,
+        seeds = [
+            COMMENT_SEED,
+            post_id.to_le_bytes().as_ref(),
+            comment_id.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub comment: UncheckedAccount<'info>,
+    
+    #[account(mut)]
+    pub user: Signer<'info>,
+}
\ No newline at end of file
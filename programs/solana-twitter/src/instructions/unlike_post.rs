@@ -1,16 +1,32 @@
 use crate::errors::ErrorCode::*;
-use crate::states::{Like, Post};
+use crate::states::{Like, Post, ProgramState, PauseCategory};
 use anchor_lang::prelude::*;
 
 pub fn unlike_post(ctx: Context<UnlikePostCtx>, post_id: u64) -> Result<()> {
+    // Reject while the program or social features are paused
+    ctx.accounts.program_state.require_not_paused(PauseCategory::Social)?;
+
     let like = &ctx.accounts.like;
-    let post = &mut ctx.accounts.post;
+    let post_info = ctx.accounts.post.to_account_info();
 
     // Verify like exists and matches user and post
     if like.user != ctx.accounts.user.key() || like.post_id != post_id {
         return Err(NotLiked.into());
     }
 
+    // A purged post's account is already closed; the like is still closed
+    // so its rent comes back, there is just no counter to update
+    if post_info.data_is_empty() {
+        return Ok(());
+    }
+
+    if *post_info.owner != crate::ID {
+        return Err(PostNotFound.into());
+    }
+
+    let mut data = post_info.try_borrow_mut_data()?;
+    let mut post = Post::try_deserialize(&mut &data[..])?;
+
     // Verify post exists
     if post.post_id != post_id {
         return Err(PostNotFound.into());
@@ -18,6 +34,7 @@ pub fn unlike_post(ctx: Context<UnlikePostCtx>, post_id: u64) -> Result<()> {
 
     // Decrement like count
     post.likes_count = post.likes_count.saturating_sub(1);
+    post.try_serialize(&mut &mut data[..])?;
 
     Ok(())
 }
@@ -25,6 +42,12 @@ pub fn unlike_post(ctx: Context<UnlikePostCtx>, post_id: u64) -> Result<()> {
 #[derive(Accounts)]
 #[instruction(post_id: u64)]
 pub struct UnlikePostCtx<'info> {
+    #[account(
+        seeds = [b"program_state"],
+        bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
     #[account(
         mut,
         close = user,
@@ -37,6 +60,8 @@ pub struct UnlikePostCtx<'info> {
     )]
     pub like: Account<'info, Like>,
     
+    /// CHECK: the post PDA, which may already be closed by purge_post;
+    /// deserialized in the handler only when it still exists
     #[account(
         mut,
         seeds = [
@@ -45,7 +70,7 @@ pub struct UnlikePostCtx<'info> {
         ],
         bump
     )]
-    pub post: Account<'info, Post>,
+    pub post: UncheckedAccount<'info>,
     
     #[account(mut)]
     pub user: Signer<'info>,
@@ -0,0 +1,31 @@
+use crate::errors::ErrorCode::*;
+use crate::states::ProgramState;
+use anchor_lang::prelude::*;
+
+pub fn update_platform_address(
+    ctx: Context<UpdatePlatformAddressCtx>,
+    new_platform_address: Pubkey,
+) -> Result<()> {
+    let state = &mut ctx.accounts.program_state;
+
+    // Only the admin can change where fees are sent
+    if state.admin != ctx.accounts.admin.key() {
+        return Err(UnauthorizedAdmin.into());
+    }
+
+    state.platform_address = new_platform_address;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdatePlatformAddressCtx<'info> {
+    #[account(
+        mut,
+        seeds = [b"program_state"],
+        bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    pub admin: Signer<'info>,
+}
\ No newline at end of file
@@ -0,0 +1,34 @@
+use crate::constants::MAX_PLATFORM_FEE_BPS;
+use crate::errors::ErrorCode::*;
+use crate::states::ProgramState;
+use anchor_lang::prelude::*;
+
+pub fn update_platform_fee(ctx: Context<UpdatePlatformFeeCtx>, new_fee: u64) -> Result<()> {
+    let state = &mut ctx.accounts.program_state;
+
+    // Only the admin can change the fee
+    if state.admin != ctx.accounts.admin.key() {
+        return Err(UnauthorizedAdmin.into());
+    }
+
+    // Fee is expressed in basis points and capped
+    if new_fee > MAX_PLATFORM_FEE_BPS {
+        return Err(PlatformFeeTooHigh.into());
+    }
+
+    state.platform_fee = new_fee;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdatePlatformFeeCtx<'info> {
+    #[account(
+        mut,
+        seeds = [b"program_state"],
+        bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    pub admin: Signer<'info>,
+}
\ No newline at end of file
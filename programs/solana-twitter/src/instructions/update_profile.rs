@@ -1,6 +1,6 @@
 use crate::constants::*;
 use crate::errors::ErrorCode::*;
-use crate::states::UserProfile;
+use crate::states::{UserProfile, ProgramState, PauseCategory};
 use anchor_lang::prelude::*;
 
 pub fn update_profile(
@@ -9,6 +9,9 @@ pub fn update_profile(
     bio: Option<String>,
     profile_image_url: Option<String>,
 ) -> Result<()> {
+    // Reject while the program or social features are paused
+    ctx.accounts.program_state.require_not_paused(PauseCategory::Social)?;
+
     let profile = &mut ctx.accounts.user_profile;
 
     // Verify user owns the profile
@@ -45,6 +48,12 @@ pub fn update_profile(
 
 #[derive(Accounts)]
 pub struct UpdateProfileCtx<'info> {
+    #[account(
+        seeds = [b"program_state"],
+        bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
     #[account(
         mut,
         seeds = [
@@ -0,0 +1,77 @@
+use crate::errors::ErrorCode::*;
+use crate::events::TreasuryWithdrawn;
+use crate::states::ProgramState;
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+
+pub fn withdraw_treasury(ctx: Context<WithdrawTreasuryCtx>, amount: u64) -> Result<()> {
+    let state = &mut ctx.accounts.program_state;
+    let treasury = &ctx.accounts.treasury;
+    let recipient = &ctx.accounts.recipient;
+
+    // Only the admin can withdraw platform fees
+    if state.admin != ctx.accounts.admin.key() {
+        return Err(UnauthorizedAdmin.into());
+    }
+
+    // Withdrawals always go to the platform fee recipient
+    if recipient.key() != state.platform_address {
+        return Err(InvalidPlatformFeeRecipient.into());
+    }
+
+    // Treasury must stay rent exempt
+    let rent_exempt_minimum = Rent::get()?.minimum_balance(0);
+    let available = treasury.lamports().saturating_sub(rent_exempt_minimum);
+    if amount == 0 || amount > available {
+        return Err(InsufficientTreasuryBalance.into());
+    }
+
+    let treasury_seeds: &[&[u8]] = &[b"treasury", &[state.treasury_bump]];
+    transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: treasury.to_account_info(),
+                to: recipient.to_account_info(),
+            },
+            &[treasury_seeds],
+        ),
+        amount,
+    )?;
+
+    state.total_fees_withdrawn += amount;
+
+    emit!(TreasuryWithdrawn {
+        admin: ctx.accounts.admin.key(),
+        recipient: recipient.key(),
+        mint: None,
+        amount,
+        timestamp: Clock::get()?.unix_timestamp as u64,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct WithdrawTreasuryCtx<'info> {
+    #[account(
+        mut,
+        seeds = [b"program_state"],
+        bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump = program_state.treasury_bump
+    )]
+    pub treasury: SystemAccount<'info>,
+    
+    #[account(mut)]
+    pub recipient: SystemAccount<'info>,
+    
+    pub admin: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
\ No newline at end of file
@@ -0,0 +1,120 @@
+use crate::constants::*;
+use crate::errors::ErrorCode::*;
+use crate::events::TreasuryWithdrawn;
+use crate::states::{ProgramState, TreasuryTokenTotal};
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+pub fn withdraw_treasury_token(
+    ctx: Context<WithdrawTreasuryTokenCtx>,
+    amount: u64,
+) -> Result<()> {
+    let state = &ctx.accounts.program_state;
+    let treasury_token_account = &ctx.accounts.treasury_token_account;
+    let mint = &ctx.accounts.mint;
+    let treasury_total = &mut ctx.accounts.treasury_token_total;
+
+    // Only the admin can withdraw platform fees
+    if state.admin != ctx.accounts.admin.key() {
+        return Err(UnauthorizedAdmin.into());
+    }
+
+    // Withdrawals always go to the platform fee recipient
+    if ctx.accounts.recipient.key() != state.platform_address {
+        return Err(InvalidPlatformFeeRecipient.into());
+    }
+
+    if amount == 0 || amount > treasury_token_account.amount {
+        return Err(InsufficientTreasuryBalance.into());
+    }
+
+    let treasury_seeds: &[&[u8]] = &[b"treasury", &[state.treasury_bump]];
+    transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: treasury_token_account.to_account_info(),
+                mint: mint.to_account_info(),
+                to: ctx.accounts.recipient_token_account.to_account_info(),
+                authority: ctx.accounts.treasury.to_account_info(),
+            },
+            &[treasury_seeds],
+        ),
+        amount,
+        mint.decimals,
+    )?;
+
+    // Tokens sent straight to the treasury have no collected total yet
+    treasury_total.mint = mint.key();
+    treasury_total.fees_withdrawn += amount;
+
+    emit!(TreasuryWithdrawn {
+        admin: ctx.accounts.admin.key(),
+        recipient: ctx.accounts.recipient.key(),
+        mint: Some(mint.key()),
+        amount,
+        timestamp: Clock::get()?.unix_timestamp as u64,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct WithdrawTreasuryTokenCtx<'info> {
+    #[account(
+        seeds = [b"program_state"],
+        bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(
+        seeds = [b"treasury"],
+        bump = program_state.treasury_bump
+    )]
+    pub treasury: SystemAccount<'info>,
+
+    pub recipient: SystemAccount<'info>,
+
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = treasury,
+        associated_token::token_program = token_program
+    )]
+    pub treasury_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = ANCHOR_DISCRIMINATOR_SIZE + TreasuryTokenTotal::INIT_SPACE,
+        seeds = [
+            b"treasury_token_total",
+            mint.key().as_ref(),
+        ],
+        bump
+    )]
+    pub treasury_token_total: Box<Account<'info, TreasuryTokenTotal>>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        associated_token::mint = mint,
+        associated_token::authority = recipient,
+        associated_token::token_program = token_program
+    )]
+    pub recipient_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    pub system_program: Program<'info, System>,
+}
\ No newline at end of file
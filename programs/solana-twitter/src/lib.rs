@@ -4,10 +4,13 @@ use anchor_lang::prelude::*;
 
 pub mod constants;
 pub mod errors;
+pub mod events;
 pub mod instructions;
 pub mod states;
+pub mod validation;
 
 use instructions::*;
+use states::{CollaboratorInvite, ReactionKind};
 
 declare_id!("72bRGCehS6GpgRDoUpEtdtw2PV5nJhiEZ2SiuzUwR3Cz");
 
@@ -37,10 +40,47 @@ pub mod twitter_platform {
         create_post::create_post(ctx, content, image_url)
     }
 
-    pub fn delete_post(ctx: Context<DeletePostCtx>, post_id: u64) -> Result<()> {
+    pub fn create_reply(
+        ctx: Context<CreateReplyCtx>,
+        parent_post_id: u64,
+        content: String,
+        image_url: Option<String>,
+    ) -> Result<()> {
+        create_reply::create_reply(ctx, parent_post_id, content, image_url)
+    }
+
+    pub fn quote_post(
+        ctx: Context<QuotePostCtx>,
+        quoted_post_id: u64,
+        content: String,
+        image_url: Option<String>,
+    ) -> Result<()> {
+        quote_post::quote_post(ctx, quoted_post_id, content, image_url)
+    }
+
+    pub fn delete_post<'info>(
+        ctx: Context<'_, '_, 'info, 'info, DeletePostCtx<'info>>,
+        post_id: u64,
+    ) -> Result<()> {
         delete_post::delete_post(ctx, post_id)
     }
 
+    pub fn purge_post<'info>(
+        ctx: Context<'_, '_, 'info, 'info, PurgePostCtx<'info>>,
+        post_id: u64,
+    ) -> Result<()> {
+        purge_post::purge_post(ctx, post_id)
+    }
+
+    pub fn edit_post(
+        ctx: Context<EditPostCtx>,
+        post_id: u64,
+        content: String,
+        image_url: Option<String>,
+    ) -> Result<()> {
+        edit_post::edit_post(ctx, post_id, content, image_url)
+    }
+
     pub fn follow_user(ctx: Context<FollowUserCtx>, target_user: Pubkey) -> Result<()> {
         follow_user::follow_user(ctx, target_user)
     }
@@ -57,30 +97,80 @@ pub mod twitter_platform {
         unlike_post::unlike_post(ctx, post_id)
     }
 
+    pub fn repost(ctx: Context<RepostCtx>, post_id: u64) -> Result<()> {
+        repost::repost(ctx, post_id)
+    }
+
+    pub fn undo_repost(ctx: Context<UndoRepostCtx>, post_id: u64) -> Result<()> {
+        undo_repost::undo_repost(ctx, post_id)
+    }
+
+    pub fn react_to_post(
+        ctx: Context<ReactToPostCtx>,
+        post_id: u64,
+        kind: ReactionKind,
+    ) -> Result<()> {
+        react_to_post::react_to_post(ctx, post_id, kind)
+    }
+
+    pub fn remove_reaction(ctx: Context<RemoveReactionCtx>, post_id: u64) -> Result<()> {
+        remove_reaction::remove_reaction(ctx, post_id)
+    }
+
     pub fn create_comment(
         ctx: Context<CreateCommentCtx>,
         post_id: u64,
-        comment_id: u64,
         content: String,
+        parent_comment_id: Option<u64>,
     ) -> Result<()> {
-        create_comment::create_comment(ctx, post_id, comment_id, content)
+        create_comment::create_comment(ctx, post_id, content, parent_comment_id)
     }
 
     pub fn donate_to_creator(
         ctx: Context<DonateToCreatorCtx>,
         creator: Pubkey,
         amount: u64,
+        message: Option<String>,
+        is_anonymous: bool,
     ) -> Result<()> {
-        donate_to_creator::donate_to_creator(ctx, creator, amount)
+        donate_to_creator::donate_to_creator(ctx, creator, amount, message, is_anonymous)
+    }
+
+    pub fn donate_token_to_creator(
+        ctx: Context<DonateTokenToCreatorCtx>,
+        creator: Pubkey,
+        amount: u64,
+        message: Option<String>,
+        is_anonymous: bool,
+    ) -> Result<()> {
+        donate_token_to_creator::donate_token_to_creator(ctx, creator, amount, message, is_anonymous)
+    }
+
+    pub fn tip_post<'info>(
+        ctx: Context<'_, '_, 'info, 'info, TipPostCtx<'info>>,
+        post_id: u64,
+        amount: u64,
+        message: Option<String>,
+        is_anonymous: bool,
+    ) -> Result<()> {
+        tip_post::tip_post(ctx, post_id, amount, message, is_anonymous)
     }
 
     pub fn create_collaboration_post(
         ctx: Context<CreateCollaborationPostCtx>,
-        collaborator: Pubkey,
+        collaborators: Vec<CollaboratorInvite>,
         content: String,
         image_url: Option<String>,
     ) -> Result<()> {
-        create_collaboration_post::create_collaboration_post(ctx, collaborator, content, image_url)
+        create_collaboration_post::create_collaboration_post(ctx, collaborators, content, image_url)
+    }
+
+    pub fn accept_collaboration(ctx: Context<AcceptCollaborationCtx>, post_id: u64) -> Result<()> {
+        accept_collaboration::accept_collaboration(ctx, post_id)
+    }
+
+    pub fn reject_collaboration(ctx: Context<RejectCollaborationCtx>, post_id: u64) -> Result<()> {
+        reject_collaboration::reject_collaboration(ctx, post_id)
     }
 
      pub fn update_profile(
@@ -92,7 +182,89 @@ pub mod twitter_platform {
         update_profile::update_profile(ctx, display_name, bio, profile_image_url)
     }
 
-    pub fn delete_comment(ctx: Context<DeleteCommentCtx>, comment_id: u64) -> Result<()> {
-        delete_comment::delete_comment(ctx, comment_id)
+    pub fn change_username(ctx: Context<ChangeUsernameCtx>, new_username: String) -> Result<()> {
+        change_username::change_username(ctx, new_username)
+    }
+
+    pub fn delete_comment(
+        ctx: Context<DeleteCommentCtx>,
+        post_id: u64,
+        comment_id: u64,
+    ) -> Result<()> {
+        delete_comment::delete_comment(ctx, post_id, comment_id)
+    }
+
+    pub fn edit_comment(
+        ctx: Context<EditCommentCtx>,
+        post_id: u64,
+        comment_id: u64,
+        content: String,
+    ) -> Result<()> {
+        edit_comment::edit_comment(ctx, post_id, comment_id, content)
+    }
+
+    pub fn set_comment_hidden(
+        ctx: Context<SetCommentHiddenCtx>,
+        post_id: u64,
+        comment_id: u64,
+        hidden: bool,
+    ) -> Result<()> {
+        set_comment_hidden::set_comment_hidden(ctx, post_id, comment_id, hidden)
+    }
+
+    pub fn like_comment(
+        ctx: Context<LikeCommentCtx>,
+        post_id: u64,
+        comment_id: u64,
+    ) -> Result<()> {
+        like_comment::like_comment(ctx, post_id, comment_id)
+    }
+
+    pub fn unlike_comment(
+        ctx: Context<UnlikeCommentCtx>,
+        post_id: u64,
+        comment_id: u64,
+    ) -> Result<()> {
+        unlike_comment::unlike_comment(ctx, post_id, comment_id)
+    }
+
+    pub fn update_platform_fee(ctx: Context<UpdatePlatformFeeCtx>, new_fee: u64) -> Result<()> {
+        update_platform_fee::update_platform_fee(ctx, new_fee)
+    }
+
+    pub fn update_platform_address(
+        ctx: Context<UpdatePlatformAddressCtx>,
+        new_platform_address: Pubkey,
+    ) -> Result<()> {
+        update_platform_address::update_platform_address(ctx, new_platform_address)
+    }
+
+    pub fn propose_admin(ctx: Context<ProposeAdminCtx>, new_admin: Pubkey) -> Result<()> {
+        propose_admin::propose_admin(ctx, new_admin)
+    }
+
+    pub fn accept_admin(ctx: Context<AcceptAdminCtx>) -> Result<()> {
+        accept_admin::accept_admin(ctx)
+    }
+
+    pub fn set_paused(
+        ctx: Context<SetPausedCtx>,
+        paused: Option<bool>,
+        posting_paused: Option<bool>,
+        social_paused: Option<bool>,
+        payments_paused: Option<bool>,
+    ) -> Result<()> {
+        set_paused::set_paused(ctx, paused, posting_paused, social_paused, payments_paused)
+    }
+
+    pub fn withdraw_treasury(ctx: Context<WithdrawTreasuryCtx>, amount: u64) -> Result<()> {
+        withdraw_treasury::withdraw_treasury(ctx, amount)
+    }
+
+    pub fn withdraw_treasury_token(
+        ctx: Context<WithdrawTreasuryTokenCtx>,
+        amount: u64,
+    ) -> Result<()> {
+        withdraw_treasury_token::withdraw_treasury_token(ctx, amount)
     }
 }
\ No newline at end of file
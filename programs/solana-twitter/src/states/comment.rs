@@ -6,8 +6,31 @@ pub struct Comment {
     pub comment_id: u64,
     pub post_id: u64,
     pub author: Pubkey,
+    pub parent_comment_id: Option<u64>, // None for top-level comments
+    pub depth: u8,
     #[max_len(140)]
     pub content: String,
+    pub likes_count: u64,
+    pub replies_count: u64,
     pub created_at: u64,
-    pub is_deleted: bool,
+    pub edited_at: u64,
+    pub is_edited: bool,
+    pub is_hidden: bool, // hidden by the post author or collaborator
+}
+
+// Comments live at [COMMENT_SEED, post_id, comment_id]; every comment
+// instruction derives the address the same way
+pub const COMMENT_SEED: &[u8] = b"comment";
+
+impl Comment {
+    pub fn find_address(post_id: u64, comment_id: u64) -> (Pubkey, u8) {
+        Pubkey::find_program_address(
+            &[
+                COMMENT_SEED,
+                post_id.to_le_bytes().as_ref(),
+                comment_id.to_le_bytes().as_ref(),
+            ],
+            &crate::ID,
+        )
+    }
 }
\ No newline at end of file
@@ -0,0 +1,10 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct CommentLike {
+    pub user: Pubkey,
+    pub post_id: u64,
+    pub comment_id: u64,
+    pub created_at: u64,
+}
\ No newline at end of file
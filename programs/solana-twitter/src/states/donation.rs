@@ -3,10 +3,14 @@ use anchor_lang::prelude::*;
 #[account]
 #[derive(InitSpace)]
 pub struct Donation {
-    pub donor: Pubkey,
+    pub donation_id: u64, // sequence number within the creator's receipts
+    pub donor: Pubkey, // Pubkey::default() for anonymous donations
     pub recipient: Pubkey,
     pub amount: u64,
+    pub mint: Option<Pubkey>, // None for SOL donations
+    pub post_id: Option<u64>, // set for tips made through tip_post
     pub timestamp: u64,
-    #[max_len(64)]
-    pub transaction_id: String,
+    #[max_len(140)]
+    pub message: Option<String>,
+    pub is_anonymous: bool,
 }
\ No newline at end of file
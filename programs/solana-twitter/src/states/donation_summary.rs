@@ -0,0 +1,13 @@
+use anchor_lang::prelude::*;
+
+// Only named donations are summarized here; anonymous gifts never touch the
+// account, so its address and contents cannot tie them to the donor
+#[account]
+#[derive(InitSpace)]
+pub struct DonationSummary {
+    pub donor: Pubkey,
+    pub recipient: Pubkey,
+    pub donation_count: u64,
+    pub total_amount: u64, // lamports; token donations only bump the count
+    pub last_donation_at: u64,
+}
\ No newline at end of file
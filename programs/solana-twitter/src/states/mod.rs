@@ -1,15 +1,33 @@
 pub mod program_state;
 pub mod user_profile;
 pub mod post;
+pub mod post_revision;
+pub mod post_tombstone;
 pub mod comment;
+pub mod comment_like;
 pub mod follow;
 pub mod like;
+pub mod repost;
+pub mod reaction;
 pub mod donation;
+pub mod donation_summary;
+pub mod username_record;
+pub mod token_tip_total;
+pub mod treasury_token_total;
 
 pub use program_state::*;
 pub use user_profile::*;
 pub use post::*;
+pub use post_revision::*;
+pub use post_tombstone::*;
 pub use comment::*;
+pub use comment_like::*;
 pub use follow::*;
 pub use like::*;
-pub use donation::*;
\ No newline at end of file
+pub use repost::*;
+pub use reaction::*;
+pub use donation::*;
+pub use donation_summary::*;
+pub use username_record::*;
+pub use token_tip_total::*;
+pub use treasury_token_total::*;
\ No newline at end of file
@@ -1,19 +1,103 @@
+use crate::constants::{MAX_COLLABORATORS, REACTION_KIND_COUNT};
 use anchor_lang::prelude::*;
 
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum CollaborationStatus {
+    Pending,
+    Accepted,
+    Rejected,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub struct Collaborator {
+    pub key: Pubkey,
+    pub status: CollaborationStatus,
+    pub share_bps: u16, // cut of net tips on this post; the author keeps the rest
+}
+
+// Instruction input for create_collaboration_post
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct CollaboratorInvite {
+    pub key: Pubkey,
+    pub share_bps: u16,
+}
+
 #[account]
 #[derive(InitSpace)]
 pub struct Post {
     pub post_id: u64,
     pub author: Pubkey,
-    pub collaborator: Option<Pubkey>, // for collaboration posts
+    #[max_len(MAX_COLLABORATORS)]
+    pub collaborators: Vec<Collaborator>, // invited co-authors, empty for regular posts
+    pub reply_to: Option<u64>, // parent post_id for replies
+    pub root_post_id: u64, // first post of the thread, own post_id for top-level posts
+    pub quote_of: Option<u64>, // quoted post_id for quote posts
     #[max_len(280)]
     pub content: String,
     #[max_len(256)]
     pub image_url: Option<String>,
     pub likes_count: u64,
+    pub reaction_counts: [u64; REACTION_KIND_COUNT], // indexed by ReactionKind
     pub comments_count: u64,
+    pub comment_id_counter: u64, // last comment_id assigned on this post, never decremented
+    pub replies_count: u64,
+    pub reposts_count: u64,
+    pub quotes_count: u64,
+    pub tips_total: u64, // gross lamports tipped through tip_post
     pub created_at: u64,
     pub updated_at: u64,
+    pub edit_count: u64, // number of PostRevision snapshots for this post
     pub is_deleted: bool,
     pub is_collaboration: bool,
+}
+
+impl Post {
+    // Fresh top-level post with every counter zeroed; callers then set the
+    // reply, quote or collaborator links for their kind of post
+    pub fn initialize(
+        &mut self,
+        post_id: u64,
+        author: Pubkey,
+        content: String,
+        image_url: Option<String>,
+        created_at: u64,
+    ) {
+        self.post_id = post_id;
+        self.author = author;
+        self.collaborators = Vec::new();
+        self.reply_to = None;
+        self.root_post_id = post_id;
+        self.quote_of = None;
+        self.content = content;
+        self.image_url = image_url;
+        self.likes_count = 0;
+        self.reaction_counts = [0; REACTION_KIND_COUNT];
+        self.comments_count = 0;
+        self.comment_id_counter = 0;
+        self.replies_count = 0;
+        self.reposts_count = 0;
+        self.quotes_count = 0;
+        self.tips_total = 0;
+        self.created_at = created_at;
+        self.updated_at = created_at;
+        self.edit_count = 0;
+        self.is_deleted = false;
+        self.is_collaboration = false;
+    }
+
+    // Author or any accepted collaborator; all of them may manage the post and its comments
+    pub fn is_owner(&self, user: &Pubkey) -> bool {
+        self.author == *user || self.accepted_collaborators().any(|c| c.key == *user)
+    }
+
+    // Co-authors who agreed to the post, in invite order
+    pub fn accepted_collaborators(&self) -> impl Iterator<Item = &Collaborator> {
+        self.collaborators
+            .iter()
+            .filter(|c| c.status == CollaborationStatus::Accepted)
+    }
+
+    pub fn collaborator_mut(&mut self, user: &Pubkey) -> Option<&mut Collaborator> {
+        self.collaborators.iter_mut().find(|c| c.key == *user)
+    }
 }
\ No newline at end of file
@@ -0,0 +1,15 @@
+use anchor_lang::prelude::*;
+
+// Snapshot of a post taken right before an edit replaced it
+#[account]
+#[derive(InitSpace)]
+pub struct PostRevision {
+    pub post_id: u64,
+    pub revision: u64, // 1 for the original content, increasing with each edit
+    pub editor: Pubkey,
+    #[max_len(280)]
+    pub content: String,
+    #[max_len(256)]
+    pub image_url: Option<String>,
+    pub replaced_at: u64,
+}
\ No newline at end of file
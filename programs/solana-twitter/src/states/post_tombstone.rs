@@ -0,0 +1,10 @@
+use anchor_lang::prelude::*;
+
+// Left behind when a post account is closed so its post_id still resolves
+#[account]
+#[derive(InitSpace)]
+pub struct PostTombstone {
+    pub post_id: u64,
+    pub author: Pubkey,
+    pub deleted_at: u64,
+}
\ No newline at end of file
@@ -1,3 +1,5 @@
+use crate::constants::FEE_BASIS_POINTS;
+use crate::errors::ErrorCode::{MathOverflow, ProgramPaused};
 use anchor_lang::prelude::*;
 
 #[account]
@@ -7,7 +9,79 @@ pub struct ProgramState {
     pub user_count: u64,
     pub post_count: u64,
     pub comment_count: u64,
-    pub platform_fee: u64, // percentage fee for donations
-    pub platform_address: Pubkey,
+    pub platform_fee: u64, // fee for donations, in basis points
+    pub platform_address: Pubkey, // receives treasury withdrawals
+    pub admin: Pubkey,
+    pub pending_admin: Option<Pubkey>, // set by propose_admin, cleared by accept_admin
     pub total_donations: u64,
+    pub treasury_bump: u8, // PDA [b"treasury"] that collects platform fees
+    pub total_fees_collected: u64, // SOL fees paid into the treasury; token fees are in TreasuryTokenTotal
+    pub total_fees_withdrawn: u64, // SOL withdrawn from the treasury
+    pub paused: bool, // emergency freeze for every non-admin instruction
+    pub posting_paused: bool,
+    pub social_paused: bool,
+    pub payments_paused: bool,
+}
+
+// Instruction groups that can be frozen independently of the global switch
+pub enum PauseCategory {
+    Posting,  // posts, comments, collaborations
+    Social,   // profiles, follows, likes
+    Payments, // donations
+}
+
+impl ProgramState {
+    pub fn require_not_paused(&self, category: PauseCategory) -> Result<()> {
+        let category_paused = match category {
+            PauseCategory::Posting => self.posting_paused,
+            PauseCategory::Social => self.social_paused,
+            PauseCategory::Payments => self.payments_paused,
+        };
+
+        if self.paused || category_paused {
+            return Err(ProgramPaused.into());
+        }
+
+        Ok(())
+    }
+
+    // Platform cut of a donation or tip, in the same units as amount. The
+    // product is taken in u128 so large token amounts do not overflow
+    pub fn platform_fee_for(&self, amount: u64) -> Result<u64> {
+        let fee = amount as u128 * self.platform_fee as u128 / FEE_BASIS_POINTS as u128;
+        u64::try_from(fee).map_err(|_| MathOverflow.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn state_with_fee(platform_fee: u64) -> ProgramState {
+        ProgramState {
+            initialized: true,
+            user_count: 0,
+            post_count: 0,
+            comment_count: 0,
+            platform_fee,
+            platform_address: Pubkey::default(),
+            admin: Pubkey::default(),
+            pending_admin: None,
+            total_donations: 0,
+            treasury_bump: 0,
+            total_fees_collected: 0,
+            total_fees_withdrawn: 0,
+            paused: false,
+            posting_paused: false,
+            social_paused: false,
+            payments_paused: false,
+        }
+    }
+
+    #[test]
+    fn platform_fee_handles_amounts_near_u64_max() {
+        let state = state_with_fee(500);
+        assert_eq!(state.platform_fee_for(10_000).unwrap(), 500);
+        assert_eq!(state.platform_fee_for(u64::MAX).unwrap(), u64::MAX / 20);
+    }
 }
\ No newline at end of file
@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+
+// Likes stay on like_post/unlike_post and Post.likes_count, so there is no
+// Like kind here for a user to be counted twice through
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum ReactionKind {
+    Love,
+    Laugh,
+    Wow,
+    Sad,
+    Angry,
+}
+
+impl ReactionKind {
+    // Slot in Post.reaction_counts
+    pub fn index(self) -> usize {
+        self as usize
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Reaction {
+    pub user: Pubkey,
+    pub post_id: u64,
+    pub kind: ReactionKind,
+    pub created_at: u64,
+}
\ No newline at end of file
@@ -0,0 +1,9 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct Repost {
+    pub user: Pubkey,
+    pub post_id: u64,
+    pub created_at: u64,
+}
\ No newline at end of file
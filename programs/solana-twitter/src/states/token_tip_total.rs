@@ -0,0 +1,10 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct TokenTipTotal {
+    pub creator: Pubkey,
+    pub mint: Pubkey,
+    pub total_received: u64, // net of platform fee, in base units of the mint
+    pub tip_count: u64,
+}
\ No newline at end of file
@@ -0,0 +1,9 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct TreasuryTokenTotal {
+    pub mint: Pubkey,
+    pub fees_collected: u64, // token fees paid into the treasury, in base units of the mint
+    pub fees_withdrawn: u64, // token fees withdrawn from the treasury
+}
\ No newline at end of file
@@ -18,5 +18,7 @@ pub struct UserProfile {
     pub posts_count: u64,
     pub created_at: u64,
     pub total_donations_received: u64,
+    pub donations_received_count: u64, // receipts issued to this creator; also the id of the latest Donation
     pub is_verified: bool,
+    pub username_changed_at: u64, // 0 until the first change_username
 }
\ No newline at end of file
@@ -0,0 +1,11 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct UsernameRecord {
+    pub owner: Pubkey,
+    pub profile: Pubkey, // UserProfile PDA that holds this handle
+    #[max_len(20)]
+    pub username: String,
+    pub created_at: u64,
+}
\ No newline at end of file
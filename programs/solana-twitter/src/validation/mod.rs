@@ -0,0 +1,100 @@
+use crate::constants::*;
+use crate::errors::ErrorCode::*;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+
+// Case-fold a handle so "Alice" and "alice" claim the same registry entry
+pub fn normalize_username(username: &str) -> String {
+    username.to_ascii_lowercase()
+}
+
+// Registry PDA seed for a handle. Hashing keeps the seed at 32 bytes however
+// long the raw input is, so oversized handles reach validate_username and fail
+// with UsernameTooLong instead of aborting address derivation
+pub fn username_seed(username: &str) -> [u8; 32] {
+    hash(normalize_username(username).as_bytes()).to_bytes()
+}
+
+// Enforce the handle grammar on an already normalized username:
+// 3-20 characters of a-z, 0-9 and underscore, and not a reserved name
+pub fn validate_username(username: &str) -> Result<()> {
+    if username.len() < MIN_USERNAME_LENGTH {
+        return Err(UsernameTooShort.into());
+    }
+    if username.len() > MAX_USERNAME_LENGTH {
+        return Err(UsernameTooLong.into());
+    }
+
+    let valid_chars = username
+        .bytes()
+        .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'_');
+    if !valid_chars {
+        return Err(InvalidUsernameCharacters.into());
+    }
+
+    if RESERVED_USERNAMES.contains(&username) {
+        return Err(UsernameReserved.into());
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(username: &str) -> Result<()> {
+        validate_username(&normalize_username(username))
+    }
+
+    #[test]
+    fn accepts_valid_handles() {
+        assert!(check("alice").is_ok());
+        assert!(check("bob_42").is_ok());
+        assert!(check("___").is_ok());
+        assert!(check("abc").is_ok());
+        assert!(check("a_twenty_char_handle").is_ok());
+    }
+
+    #[test]
+    fn case_folds_before_validation() {
+        assert_eq!(normalize_username("Alice_W"), "alice_w");
+        assert!(check("Alice_W").is_ok());
+    }
+
+    #[test]
+    fn rejects_bad_lengths() {
+        assert_eq!(check(""), Err(UsernameTooShort.into()));
+        assert_eq!(check("ab"), Err(UsernameTooShort.into()));
+        assert_eq!(check("a_twenty_one_chars_xx"), Err(UsernameTooLong.into()));
+    }
+
+    #[test]
+    fn rejects_characters_outside_the_grammar() {
+        assert_eq!(check("alice bob"), Err(InvalidUsernameCharacters.into()));
+        assert_eq!(check(" alice"), Err(InvalidUsernameCharacters.into()));
+        assert_eq!(check("alice.sol"), Err(InvalidUsernameCharacters.into()));
+        assert_eq!(check("alice-bob"), Err(InvalidUsernameCharacters.into()));
+        assert_eq!(check("ålice"), Err(InvalidUsernameCharacters.into()));
+        // Cyrillic "а" homoglyph
+        assert_eq!(check("\u{430}lice"), Err(InvalidUsernameCharacters.into()));
+        assert_eq!(check("alice🚀"), Err(InvalidUsernameCharacters.into()));
+    }
+
+    #[test]
+    fn username_seed_is_fixed_length_and_case_insensitive() {
+        assert_eq!(username_seed("Alice"), username_seed("alice"));
+        assert_ne!(username_seed("alice"), username_seed("alice_w"));
+        // Longer than the 32-byte seed limit, still a valid seed
+        let long = "x".repeat(40);
+        assert_eq!(username_seed(&long).len(), 32);
+        assert_eq!(check(&long), Err(UsernameTooLong.into()));
+    }
+
+    #[test]
+    fn rejects_reserved_names_in_any_case() {
+        assert_eq!(check("admin"), Err(UsernameReserved.into()));
+        assert_eq!(check("Solana"), Err(UsernameReserved.into()));
+        assert!(check("admin_fan").is_ok());
+    }
+}
\ No newline at end of file
@@ -1,16 +1,1886 @@
 import * as anchor from "@coral-xyz/anchor";
 import { Program } from "@coral-xyz/anchor";
-import { SolanaTwitter } from "../target/types/solana_twitter";
+import {
+  createMint,
+  getAccount,
+  getAssociatedTokenAddressSync,
+  getOrCreateAssociatedTokenAccount,
+  mintTo,
+} from "@solana/spl-token";
+import { assert } from "chai";
+import { TwitterPlatform } from "../target/types/twitter_platform";
+
+// web3 comes through anchor so the tests only rely on declared dependencies
+const { Keypair, LAMPORTS_PER_SOL, PublicKey } = anchor.web3;
+type Keypair = anchor.web3.Keypair;
+type PublicKey = anchor.web3.PublicKey;
 
 describe("solana-twitter", () => {
   // Configure the client to use the local cluster.
-  anchor.setProvider(anchor.AnchorProvider.env());
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.twitterPlatform as Program<TwitterPlatform>;
+  const deployer = provider.wallet as anchor.Wallet;
+
+  const [programStatePda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("program_state")],
+    program.programId
+  );
+
+  const airdrop = async (to: PublicKey, sol = 2) => {
+    const sig = await provider.connection.requestAirdrop(
+      to,
+      sol * LAMPORTS_PER_SOL
+    );
+    await provider.connection.confirmTransaction(sig, "confirmed");
+  };
+
+  const newUser = async () => {
+    const user = Keypair.generate();
+    await airdrop(user.publicKey);
+    return user;
+  };
+
+  const profilePda = (owner: PublicKey) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("user_profile"), owner.toBuffer()],
+      program.programId
+    )[0];
+
+  const usernamePda = (username: string) =>
+    PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("username"),
+        Buffer.from(anchor.utils.sha256.hash(username.toLowerCase()), "hex"),
+      ],
+      program.programId
+    )[0];
+
+  const u64 = (value: number | anchor.BN) =>
+    new anchor.BN(value).toArrayLike(Buffer, "le", 8);
+
+  const postPda = (postId: number | anchor.BN) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("post"), u64(postId)],
+      program.programId
+    )[0];
+
+  const commentPda = (
+    postId: number | anchor.BN,
+    commentId: number | anchor.BN
+  ) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("comment"), u64(postId), u64(commentId)],
+      program.programId
+    )[0];
+
+  const createProfile = (user: Keypair, username: string) =>
+    program.methods
+      .createProfile(username, username, "", "")
+      .accounts({
+        programState: programStatePda,
+        usernameRecord: usernamePda(username),
+        user: user.publicKey,
+      })
+      .signers([user])
+      .rpc();
+
+  const createPost = async (user: Keypair, content = "gm") => {
+    const state = await program.account.programState.fetch(programStatePda);
+    const postId = state.postCount.addn(1);
+    await program.methods
+      .createPost(content, null)
+      .accountsPartial({
+        programState: programStatePda,
+        userProfile: profilePda(user.publicKey),
+        post: postPda(postId),
+        user: user.publicKey,
+      })
+      .signers([user])
+      .rpc();
+    return postId;
+  };
+
+  const createReply = async (
+    user: Keypair,
+    parentId: anchor.BN,
+    content = "re: gm"
+  ) => {
+    const state = await program.account.programState.fetch(programStatePda);
+    const postId = state.postCount.addn(1);
+    await program.methods
+      .createReply(parentId, content, null)
+      .accountsPartial({
+        programState: programStatePda,
+        userProfile: profilePda(user.publicKey),
+        parentPost: postPda(parentId),
+        post: postPda(postId),
+        user: user.publicKey,
+      })
+      .signers([user])
+      .rpc();
+    return postId;
+  };
+
+  // Passes the profiles of `accepted` collaborators as remaining accounts
+  const deletePost = async (
+    user: Keypair,
+    postId: anchor.BN,
+    accepted: Keypair[] = []
+  ) => {
+    const post = await program.account.post.fetch(postPda(postId));
+    return program.methods
+      .deletePost(postId)
+      .accountsPartial({
+        programState: programStatePda,
+        post: postPda(postId),
+        parentPost: post.replyTo ? postPda(post.replyTo) : null,
+        quotedPost: post.quoteOf ? postPda(post.quoteOf) : null,
+        authorProfile: profilePda(post.author),
+        user: user.publicKey,
+      })
+      .remainingAccounts(
+        accepted.map((c) => ({
+          pubkey: profilePda(c.publicKey),
+          isSigner: false,
+          isWritable: true,
+        }))
+      )
+      .signers([user])
+      .rpc();
+  };
+
+  const balance = (account: PublicKey) =>
+    provider.connection.getBalance(account, "confirmed");
 
-  const program = anchor.workspace.solanaTwitter as Program<SolanaTwitter>;
+  const createComment = async (
+    user: Keypair,
+    postId: anchor.BN,
+    content: string,
+    parentCommentId: anchor.BN | null = null
+  ) => {
+    const post = await program.account.post.fetch(postPda(postId));
+    const commentId = post.commentIdCounter.addn(1);
+    await program.methods
+      .createComment(postId, content, parentCommentId)
+      .accountsPartial({
+        programState: programStatePda,
+        post: postPda(postId),
+        comment: commentPda(postId, commentId),
+        parentComment: parentCommentId
+          ? commentPda(postId, parentCommentId)
+          : null,
+        user: user.publicKey,
+      })
+      .signers([user])
+      .rpc();
+    return commentId;
+  };
+
+  const deleteComment = async (
+    user: Keypair,
+    postId: anchor.BN,
+    commentId: anchor.BN
+  ) => {
+    const comment = await program.account.comment.fetch(
+      commentPda(postId, commentId)
+    );
+    return program.methods
+      .deleteComment(postId, commentId)
+      .accountsPartial({
+        programState: programStatePda,
+        comment: commentPda(postId, commentId),
+        parentComment: comment.parentCommentId
+          ? commentPda(postId, comment.parentCommentId)
+          : null,
+        post: postPda(postId),
+        commentAuthor: comment.author,
+        user: user.publicKey,
+      })
+      .signers([user])
+      .rpc();
+  };
+
+  const treasuryPda = PublicKey.findProgramAddressSync(
+    [Buffer.from("treasury")],
+    program.programId
+  )[0];
+
+  const treasuryTokenTotalPda = (mint: PublicKey) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("treasury_token_total"), mint.toBuffer()],
+      program.programId
+    )[0];
+
+  const donationSummaryPda = (donor: PublicKey, creator: PublicKey) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("donation_summary"), donor.toBuffer(), creator.toBuffer()],
+      program.programId
+    )[0];
+
+  // Receipts are numbered per creator so their address never names the donor
+  const donationPda = (creator: PublicKey, donationId: number | anchor.BN) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("donation"), creator.toBuffer(), u64(donationId)],
+      program.programId
+    )[0];
+
+  const nextDonationId = async (creator: PublicKey) => {
+    const profile = await program.account.userProfile.fetch(
+      profilePda(creator)
+    );
+    return profile.donationsReceivedCount.addn(1);
+  };
+
+  const donate = async (
+    donor: Keypair,
+    creator: PublicKey,
+    amount: anchor.BN,
+    creatorWallet = creator,
+    {
+      message = null,
+      isAnonymous = false,
+    }: { message?: string | null; isAnonymous?: boolean } = {}
+  ) =>
+    program.methods
+      .donateToCreator(creator, amount, message, isAnonymous)
+      .accountsPartial({
+        programState: programStatePda,
+        donationSummary: isAnonymous
+          ? null
+          : donationSummaryPda(donor.publicKey, creator),
+        donation: donationPda(creator, await nextDonationId(creator)),
+        creatorProfile: profilePda(creator),
+        creatorWallet,
+        treasury: treasuryPda,
+        donor: donor.publicKey,
+      })
+      .signers([donor])
+      .rpc();
+
+  // The treasury is a PDA, so its token accounts sit off the curve
+  const tokenAccount = (mint: PublicKey, owner: PublicKey) =>
+    getAssociatedTokenAddressSync(mint, owner, true);
+
+  const tokenBalance = async (address: PublicKey) => {
+    const account = await getAccount(provider.connection, address, "confirmed");
+    return new anchor.BN(account.amount.toString());
+  };
+
+  // Creates a 6-decimal mint and funds the owner's associated token account
+  const createTestMint = async (owner: PublicKey, supply: number) => {
+    const mint = await createMint(
+      provider.connection,
+      deployer.payer,
+      deployer.publicKey,
+      null,
+      6
+    );
+    const ownerAccount = await getOrCreateAssociatedTokenAccount(
+      provider.connection,
+      deployer.payer,
+      mint,
+      owner
+    );
+    await mintTo(
+      provider.connection,
+      deployer.payer,
+      mint,
+      ownerAccount.address,
+      deployer.payer,
+      supply
+    );
+    return mint;
+  };
+
+  const donateToken = async (
+    donor: Keypair,
+    creator: PublicKey,
+    mint: PublicKey,
+    amount: anchor.BN
+  ) =>
+    program.methods
+      .donateTokenToCreator(creator, amount, null, false)
+      .accountsPartial({
+        donationSummary: donationSummaryPda(donor.publicKey, creator),
+        donation: donationPda(creator, await nextDonationId(creator)),
+        tokenTipTotal: PublicKey.findProgramAddressSync(
+          [Buffer.from("token_tip_total"), creator.toBuffer(), mint.toBuffer()],
+          program.programId
+        )[0],
+        treasuryTokenTotal: treasuryTokenTotalPda(mint),
+        creatorProfile: profilePda(creator),
+        creatorWallet: creator,
+        treasury: treasuryPda,
+        mint,
+        donorTokenAccount: tokenAccount(mint, donor.publicKey),
+        creatorTokenAccount: tokenAccount(mint, creator),
+        treasuryTokenAccount: tokenAccount(mint, treasuryPda),
+        donor: donor.publicKey,
+      })
+      .signers([donor])
+      .rpc();
+
+  const expectError = async (promise: Promise<unknown>, code: string) => {
+    try {
+      await promise;
+      assert.fail(`expected ${code}`);
+    } catch (err) {
+      assert.equal(err.error?.errorCode?.code, code);
+    }
+  };
+
+  before(async () => {
+    await program.methods
+      .initialize()
+      .accountsPartial({ treasury: treasuryPda, deployer: deployer.publicKey })
+      .rpc();
+  });
 
   it("Is initialized!", async () => {
-    // Add your test here.
-    const tx = await program.methods.initialize().rpc();
-    console.log("Your transaction signature", tx);
+    const state = await program.account.programState.fetch(programStatePda);
+    assert.isTrue(state.initialized);
+    assert.ok(state.admin.equals(deployer.publicKey));
+    assert.equal(state.userCount.toNumber(), 0);
+    assert.equal(state.postCount.toNumber(), 0);
+    assert.isFalse(state.paused);
+  });
+
+  describe("admin", () => {
+    it("initializes with the deployer as admin and fee recipient", async () => {
+      const state = await program.account.programState.fetch(programStatePda);
+      assert.ok(state.admin.equals(deployer.publicKey));
+      assert.ok(state.platformAddress.equals(deployer.publicKey));
+      assert.equal(state.platformFee.toNumber(), 500);
+      assert.isNull(state.pendingAdmin);
+    });
+
+    it("lets the admin update the platform fee within the cap", async () => {
+      await program.methods
+        .updatePlatformFee(new anchor.BN(250))
+        .accounts({ admin: deployer.publicKey })
+        .rpc();
+      let state = await program.account.programState.fetch(programStatePda);
+      assert.equal(state.platformFee.toNumber(), 250);
+
+      await expectError(
+        program.methods
+          .updatePlatformFee(new anchor.BN(1_001))
+          .accounts({ admin: deployer.publicKey })
+          .rpc(),
+        "PlatformFeeTooHigh"
+      );
+
+      await program.methods
+        .updatePlatformFee(new anchor.BN(500))
+        .accounts({ admin: deployer.publicKey })
+        .rpc();
+      state = await program.account.programState.fetch(programStatePda);
+      assert.equal(state.platformFee.toNumber(), 500);
+    });
+
+    it("rejects admin instructions from other signers", async () => {
+      const intruder = Keypair.generate();
+      await expectError(
+        program.methods
+          .updatePlatformFee(new anchor.BN(0))
+          .accounts({ admin: intruder.publicKey })
+          .signers([intruder])
+          .rpc(),
+        "UnauthorizedAdmin"
+      );
+      await expectError(
+        program.methods
+          .updatePlatformAddress(intruder.publicKey)
+          .accounts({ admin: intruder.publicKey })
+          .signers([intruder])
+          .rpc(),
+        "UnauthorizedAdmin"
+      );
+    });
+
+    it("hands over the admin role in two steps", async () => {
+      const nextAdmin = Keypair.generate();
+
+      await program.methods
+        .proposeAdmin(nextAdmin.publicKey)
+        .accounts({ admin: deployer.publicKey })
+        .rpc();
+      let state = await program.account.programState.fetch(programStatePda);
+      assert.ok(state.admin.equals(deployer.publicKey));
+      assert.ok(state.pendingAdmin.equals(nextAdmin.publicKey));
+
+      // Only the nominee can accept
+      const stranger = Keypair.generate();
+      await expectError(
+        program.methods
+          .acceptAdmin()
+          .accounts({ newAdmin: stranger.publicKey })
+          .signers([stranger])
+          .rpc(),
+        "NoPendingAdmin"
+      );
+
+      await program.methods
+        .acceptAdmin()
+        .accounts({ newAdmin: nextAdmin.publicKey })
+        .signers([nextAdmin])
+        .rpc();
+      state = await program.account.programState.fetch(programStatePda);
+      assert.ok(state.admin.equals(nextAdmin.publicKey));
+      assert.isNull(state.pendingAdmin);
+
+      // Fee recipient is unaffected by the admin handover
+      assert.ok(state.platformAddress.equals(deployer.publicKey));
+
+      // Hand the role back so the remaining tests run as the deployer
+      await program.methods
+        .proposeAdmin(deployer.publicKey)
+        .accounts({ admin: nextAdmin.publicKey })
+        .signers([nextAdmin])
+        .rpc();
+      await program.methods
+        .acceptAdmin()
+        .accounts({ newAdmin: deployer.publicKey })
+        .rpc();
+      state = await program.account.programState.fetch(programStatePda);
+      assert.ok(state.admin.equals(deployer.publicKey));
+    });
+  });
+
+  describe("pause", () => {
+    const setPaused = (flags: {
+      paused?: boolean;
+      posting?: boolean;
+      social?: boolean;
+      payments?: boolean;
+    }) =>
+      program.methods
+        .setPaused(
+          flags.paused ?? null,
+          flags.posting ?? null,
+          flags.social ?? null,
+          flags.payments ?? null
+        )
+        .accounts({ admin: deployer.publicKey })
+        .rpc();
+
+    it("only lets the admin toggle the pause flags", async () => {
+      const intruder = Keypair.generate();
+      await expectError(
+        program.methods
+          .setPaused(true, null, null, null)
+          .accounts({ admin: intruder.publicKey })
+          .signers([intruder])
+          .rpc(),
+        "UnauthorizedAdmin"
+      );
+    });
+
+    it("blocks every instruction while globally paused", async () => {
+      const user = await newUser();
+      const creator = await newUser();
+      await createProfile(creator, "paused_creator");
+      const amount = new anchor.BN(LAMPORTS_PER_SOL / 20);
+
+      await setPaused({ paused: true });
+      await expectError(createProfile(user, "paused_user"), "ProgramPaused");
+      await expectError(createPost(creator, "paused"), "ProgramPaused");
+      await expectError(
+        donate(user, creator.publicKey, amount),
+        "ProgramPaused"
+      );
+
+      await setPaused({ paused: false });
+      await createProfile(user, "paused_user");
+      await createPost(creator, "unpaused");
+      await donate(user, creator.publicKey, amount);
+    });
+
+    it("blocks only the paused category", async () => {
+      const user = await newUser();
+      const creator = await newUser();
+      await createProfile(creator, "category_creator");
+      const amount = new anchor.BN(LAMPORTS_PER_SOL / 20);
+
+      await setPaused({ social: true });
+      await expectError(createProfile(user, "social_user"), "ProgramPaused");
+      await createPost(creator, "posting still works");
+      await donate(user, creator.publicKey, amount);
+
+      await setPaused({ social: false, posting: true });
+      await expectError(createPost(creator, "paused"), "ProgramPaused");
+      await createProfile(user, "social_user");
+      await donate(user, creator.publicKey, amount);
+
+      await setPaused({ posting: false, payments: true });
+      await expectError(
+        donate(user, creator.publicKey, amount),
+        "ProgramPaused"
+      );
+      await createPost(creator, "payments paused");
+
+      await setPaused({ payments: false });
+      const state = await program.account.programState.fetch(programStatePda);
+      assert.isFalse(state.paused);
+      assert.isFalse(state.postingPaused);
+      assert.isFalse(state.socialPaused);
+      assert.isFalse(state.paymentsPaused);
+    });
+  });
+
+  describe("usernames", () => {
+    it("registers the handle and resolves it to the profile", async () => {
+      const user = await newUser();
+      await createProfile(user, "registry_one");
+
+      const record = await program.account.usernameRecord.fetch(
+        usernamePda("registry_one")
+      );
+      assert.ok(record.owner.equals(user.publicKey));
+      assert.ok(record.profile.equals(profilePda(user.publicKey)));
+      assert.equal(record.username, "registry_one");
+    });
+
+    it("rejects a handle that is already taken, ignoring case", async () => {
+      const first = await newUser();
+      const second = await newUser();
+      await createProfile(first, "registry_two");
+
+      await expectError(
+        createProfile(second, "Registry_Two"),
+        "UsernameAlreadyExists"
+      );
+    });
+
+    it("rejects handles outside the grammar", async () => {
+      const user = await newUser();
+      await expectError(
+        createProfile(user, "no spaces"),
+        "InvalidUsernameCharacters"
+      );
+      await expectError(createProfile(user, "ab"), "UsernameTooShort");
+      // Longer than a PDA seed may be; must still fail validation cleanly
+      await expectError(createProfile(user, "x".repeat(40)), "UsernameTooLong");
+      await expectError(createProfile(user, "Admin"), "UsernameReserved");
+    });
+
+    it("moves the profile to a new handle and releases the old one", async () => {
+      const user = await newUser();
+      await createProfile(user, "rename_old");
+
+      const changeUsername = (from: string, to: string) =>
+        program.methods
+          .changeUsername(to)
+          .accounts({
+            oldUsernameRecord: usernamePda(from),
+            newUsernameRecord: usernamePda(to),
+            user: user.publicKey,
+          })
+          .signers([user])
+          .rpc();
+
+      await expectError(
+        changeUsername("rename_old", "y".repeat(40)),
+        "UsernameTooLong"
+      );
+      await changeUsername("rename_old", "Rename_New");
+
+      const profile = await program.account.userProfile.fetch(
+        profilePda(user.publicKey)
+      );
+      assert.equal(profile.username, "rename_new");
+      assert.isAbove(profile.usernameChangedAt.toNumber(), 0);
+
+      const record = await program.account.usernameRecord.fetch(
+        usernamePda("rename_new")
+      );
+      assert.ok(record.owner.equals(user.publicKey));
+      assert.isNull(
+        await provider.connection.getAccountInfo(usernamePda("rename_old"))
+      );
+
+      // Changing again straight away is blocked by the cooldown
+      await expectError(
+        changeUsername("rename_new", "rename_again"),
+        "UsernameChangeCooldown"
+      );
+
+      // The released handle can be claimed by someone else
+      const other = await newUser();
+      await createProfile(other, "rename_old");
+    });
+  });
+
+  describe("posts", () => {
+    const revisionPda = (postId: anchor.BN, revision: number) =>
+      PublicKey.findProgramAddressSync(
+        [Buffer.from("post_revision"), u64(postId), u64(revision)],
+        program.programId
+      )[0];
+
+    const editPost = (
+      user: Keypair,
+      postId: anchor.BN,
+      revision: number,
+      content: string
+    ) =>
+      program.methods
+        .editPost(postId, content, null)
+        .accountsPartial({
+          post: postPda(postId),
+          revision: revisionPda(postId, revision),
+          user: user.publicKey,
+        })
+        .signers([user])
+        .rpc();
+
+    it("edits a post and keeps the replaced revisions", async () => {
+      const user = await newUser();
+      await createProfile(user, "editor_one");
+      const postId = await createPost(user, "helo world");
+
+      await editPost(user, postId, 1, "hello world");
+      await editPost(user, postId, 2, "hello, world");
+
+      const post = await program.account.post.fetch(postPda(postId));
+      assert.equal(post.content, "hello, world");
+      assert.equal(post.editCount.toNumber(), 2);
+
+      const first = await program.account.postRevision.fetch(
+        revisionPda(postId, 1)
+      );
+      const second = await program.account.postRevision.fetch(
+        revisionPda(postId, 2)
+      );
+      assert.equal(first.content, "helo world");
+      assert.equal(second.content, "hello world");
+      assert.ok(second.editor.equals(user.publicKey));
+    });
+
+    it("only lets the author edit", async () => {
+      const author = await newUser();
+      const other = await newUser();
+      await createProfile(author, "editor_two");
+      const postId = await createPost(author);
+
+      await expectError(
+        editPost(other, postId, 1, "not mine"),
+        "CannotEditOthersPost"
+      );
+    });
+
+    const tombstonePdaFor = (postId: anchor.BN) =>
+      PublicKey.findProgramAddressSync(
+        [Buffer.from("post_tombstone"), u64(postId)],
+        program.programId
+      )[0];
+
+    const purgePost = async (
+      user: Keypair,
+      postId: anchor.BN,
+      revisions: [PublicKey, PublicKey][] = []
+    ) => {
+      const post = await program.account.post.fetch(postPda(postId));
+      return program.methods
+        .purgePost(postId)
+        .accountsPartial({
+          post: postPda(postId),
+          parentPost: post.replyTo ? postPda(post.replyTo) : null,
+          quotedPost: post.quoteOf ? postPda(post.quoteOf) : null,
+          tombstone: tombstonePdaFor(postId),
+          userProfile: profilePda(user.publicKey),
+          user: user.publicKey,
+        })
+        .remainingAccounts(
+          revisions.flat().map((pubkey) => ({
+            pubkey,
+            isSigner: false,
+            isWritable: true,
+          }))
+        )
+        .signers([user])
+        .rpc();
+    };
+
+    it("purges a post, refunding rent and leaving a tombstone", async () => {
+      const user = await newUser();
+      await createProfile(user, "purger_one");
+      const postId = await createPost(user);
+      const tombstonePda = tombstonePdaFor(postId);
+
+      const postRent = await balance(postPda(postId));
+      const before = await balance(user.publicKey);
+
+      await purgePost(user, postId);
+
+      assert.isNull(
+        await provider.connection.getAccountInfo(postPda(postId))
+      );
+      const tombstone = await program.account.postTombstone.fetch(
+        tombstonePda
+      );
+      assert.equal(tombstone.postId.toNumber(), postId.toNumber());
+      assert.ok(tombstone.author.equals(user.publicKey));
+
+      // The tombstone costs far less than the post it replaces
+      const tombstoneRent = await balance(tombstonePda);
+      assert.equal(
+        (await balance(user.publicKey)) - before,
+        postRent - tombstoneRent
+      );
+
+      const profile = await program.account.userProfile.fetch(
+        profilePda(user.publicKey)
+      );
+      assert.equal(profile.postsCount.toNumber(), 0);
+    });
+
+    it("purges once comments are gone and lets likes be undone after", async () => {
+      const user = await newUser();
+      const fan = await newUser();
+      await createProfile(user, "purger_two");
+      const postId = await createPost(user, "draft");
+      await editPost(user, postId, 1, "final");
+      const commentId = await createComment(user, postId, "still here");
+
+      // Likes, reactions and reposts from others never block the purge
+      const engagementPda = (seed: string) =>
+        PublicKey.findProgramAddressSync(
+          [Buffer.from(seed), fan.publicKey.toBuffer(), u64(postId)],
+          program.programId
+        )[0];
+      const post = postPda(postId);
+      const fanKey = fan.publicKey;
+      await program.methods
+        .likePost(postId)
+        .accountsPartial({ like: engagementPda("like"), post, user: fanKey })
+        .signers([fan])
+        .rpc();
+      await program.methods
+        .reactToPost(postId, { wow: {} } as any)
+        .accountsPartial({
+          reaction: engagementPda("reaction"),
+          post,
+          user: fanKey,
+        })
+        .signers([fan])
+        .rpc();
+      await program.methods
+        .repost(postId)
+        .accountsPartial({
+          repost: engagementPda("repost"),
+          post,
+          user: fanKey,
+        })
+        .signers([fan])
+        .rpc();
+
+      await expectError(
+        purgePost(user, postId, [[revisionPda(postId, 1), user.publicKey]]),
+        "PostHasComments"
+      );
+
+      await deleteComment(user, postId, commentId);
+
+      await expectError(purgePost(user, postId), "RevisionAccountsMismatch");
+      await purgePost(user, postId, [[revisionPda(postId, 1), user.publicKey]]);
+
+      assert.isNull(await provider.connection.getAccountInfo(post));
+      assert.isNull(
+        await provider.connection.getAccountInfo(revisionPda(postId, 1))
+      );
+      const tombstone = await program.account.postTombstone.fetch(
+        tombstonePdaFor(postId)
+      );
+      assert.ok(tombstone.author.equals(user.publicKey));
+
+      // The purged post's likes, reactions and reposts still close and refund
+      await program.methods
+        .unlikePost(postId)
+        .accountsPartial({ like: engagementPda("like"), post, user: fanKey })
+        .signers([fan])
+        .rpc();
+      await program.methods
+        .removeReaction(postId)
+        .accountsPartial({
+          reaction: engagementPda("reaction"),
+          post,
+          user: fanKey,
+        })
+        .signers([fan])
+        .rpc();
+      await program.methods
+        .undoRepost(postId)
+        .accountsPartial({
+          repost: engagementPda("repost"),
+          post,
+          user: fanKey,
+        })
+        .signers([fan])
+        .rpc();
+      for (const seed of ["like", "reaction", "repost"]) {
+        assert.isNull(
+          await provider.connection.getAccountInfo(engagementPda(seed))
+        );
+      }
+    });
+
+    it("threads replies back to the root post", async () => {
+      const user = await newUser();
+      await createProfile(user, "threader_one");
+      const rootId = await createPost(user, "root");
+
+      const firstId = await createReply(user, rootId, "first reply");
+      const nestedId = await createReply(user, firstId, "reply to the reply");
+
+      const root = await program.account.post.fetch(postPda(rootId));
+      const first = await program.account.post.fetch(postPda(firstId));
+      const nested = await program.account.post.fetch(postPda(nestedId));
+
+      assert.isNull(root.replyTo);
+      assert.equal(root.rootPostId.toNumber(), rootId.toNumber());
+      assert.equal(root.repliesCount.toNumber(), 1);
+      assert.equal(first.replyTo.toNumber(), rootId.toNumber());
+      assert.equal(first.repliesCount.toNumber(), 1);
+      assert.equal(nested.replyTo.toNumber(), firstId.toNumber());
+      assert.equal(nested.rootPostId.toNumber(), rootId.toNumber());
+    });
+
+    it("releases the parent's reply count on delete and purge", async () => {
+      const user = await newUser();
+      await createProfile(user, "threader_two");
+      const rootId = await createPost(user, "root");
+      const deletedId = await createReply(user, rootId);
+      const purgedId = await createReply(user, rootId);
+      const repliesCount = async () =>
+        (
+          await program.account.post.fetch(postPda(rootId))
+        ).repliesCount.toNumber();
+      assert.equal(await repliesCount(), 2);
+
+      await deletePost(user, deletedId);
+      assert.equal(await repliesCount(), 1);
+
+      await purgePost(user, purgedId);
+      assert.equal(await repliesCount(), 0);
+
+      // Purging an already deleted reply does not count it twice
+      await purgePost(user, deletedId);
+      assert.equal(await repliesCount(), 0);
+    });
+
+    it("releases the original's quote count on delete and purge", async () => {
+      const author = await newUser();
+      const quoter = await newUser();
+      await createProfile(author, "quoted_two");
+      await createProfile(quoter, "quoter_two");
+      const originalId = await createPost(author, "original");
+      const quote = async () => {
+        const state = await program.account.programState.fetch(programStatePda);
+        const quoteId = state.postCount.addn(1);
+        await program.methods
+          .quotePost(originalId, "so true", null)
+          .accountsPartial({
+            programState: programStatePda,
+            userProfile: profilePda(quoter.publicKey),
+            quotedPost: postPda(originalId),
+            post: postPda(quoteId),
+            user: quoter.publicKey,
+          })
+          .signers([quoter])
+          .rpc();
+        return quoteId;
+      };
+      const deletedId = await quote();
+      const purgedId = await quote();
+      const quotesCount = async () =>
+        (
+          await program.account.post.fetch(postPda(originalId))
+        ).quotesCount.toNumber();
+      assert.equal(await quotesCount(), 2);
+
+      await deletePost(quoter, deletedId);
+      assert.equal(await quotesCount(), 1);
+
+      await purgePost(quoter, purgedId);
+      assert.equal(await quotesCount(), 0);
+
+      // Purging an already deleted quote does not count it twice
+      await purgePost(quoter, deletedId);
+      assert.equal(await quotesCount(), 0);
+    });
+
+    it("reposts once per user and refunds rent on undo", async () => {
+      const author = await newUser();
+      const fan = await newUser();
+      await createProfile(author, "reposted_one");
+      const postId = await createPost(author);
+      const [repostPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("repost"), fan.publicKey.toBuffer(), u64(postId)],
+        program.programId
+      );
+      const accounts = {
+        repost: repostPda,
+        post: postPda(postId),
+        user: fan.publicKey,
+      };
+
+      await program.methods
+        .repost(postId)
+        .accountsPartial(accounts)
+        .signers([fan])
+        .rpc();
+      let post = await program.account.post.fetch(postPda(postId));
+      assert.equal(post.repostsCount.toNumber(), 1);
+
+      // The PDA prevents a second repost of the same post
+      try {
+        await program.methods
+          .repost(postId)
+          .accountsPartial(accounts)
+          .signers([fan])
+          .rpc();
+        assert.fail("expected the second repost to fail");
+      } catch (err) {
+        assert.notEqual(err.message, "expected the second repost to fail");
+      }
+
+      const before = await balance(fan.publicKey);
+      const rent = await balance(repostPda);
+      await program.methods
+        .undoRepost(postId)
+        .accountsPartial(accounts)
+        .signers([fan])
+        .rpc();
+
+      post = await program.account.post.fetch(postPda(postId));
+      assert.equal(post.repostsCount.toNumber(), 0);
+      assert.equal((await balance(fan.publicKey)) - before, rent);
+    });
+
+    it("creates a quote post referencing the original", async () => {
+      const author = await newUser();
+      const quoter = await newUser();
+      await createProfile(author, "quoted_one");
+      await createProfile(quoter, "quoter_one");
+      const originalId = await createPost(author, "original");
+
+      const state = await program.account.programState.fetch(programStatePda);
+      const quoteId = state.postCount.addn(1);
+      await program.methods
+        .quotePost(originalId, "so true", null)
+        .accountsPartial({
+          programState: programStatePda,
+          userProfile: profilePda(quoter.publicKey),
+          quotedPost: postPda(originalId),
+          post: postPda(quoteId),
+          user: quoter.publicKey,
+        })
+        .signers([quoter])
+        .rpc();
+
+      const quote = await program.account.post.fetch(postPda(quoteId));
+      assert.equal(quote.quoteOf.toNumber(), originalId.toNumber());
+      assert.isNull(quote.replyTo);
+      const original = await program.account.post.fetch(postPda(originalId));
+      assert.equal(original.quotesCount.toNumber(), 1);
+    });
+
+    it("switches and removes reactions with per-kind counters", async () => {
+      const author = await newUser();
+      const fan = await newUser();
+      await createProfile(author, "reacted_one");
+      const postId = await createPost(author);
+      const [reactionPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("reaction"), fan.publicKey.toBuffer(), u64(postId)],
+        program.programId
+      );
+      const accounts = {
+        reaction: reactionPda,
+        post: postPda(postId),
+        user: fan.publicKey,
+      };
+      const react = (kind: object) =>
+        program.methods
+          .reactToPost(postId, kind as any)
+          .accountsPartial(accounts)
+          .signers([fan])
+          .rpc();
+      const counts = async () =>
+        (await program.account.post.fetch(postPda(postId))).reactionCounts.map(
+          (count) => count.toNumber()
+        );
+
+      // Order matches ReactionKind: love, laugh, wow, sad, angry
+      await react({ love: {} });
+      assert.deepEqual(await counts(), [1, 0, 0, 0, 0]);
+
+      await react({ laugh: {} });
+      assert.deepEqual(await counts(), [0, 1, 0, 0, 0]);
+
+      await expectError(react({ laugh: {} }), "AlreadyReacted");
+
+      // Plain likes are tracked separately and keep working
+      const [likePda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("like"), fan.publicKey.toBuffer(), u64(postId)],
+        program.programId
+      );
+      await program.methods
+        .likePost(postId)
+        .accountsPartial({
+          like: likePda,
+          post: postPda(postId),
+          user: fan.publicKey,
+        })
+        .signers([fan])
+        .rpc();
+      const post = await program.account.post.fetch(postPda(postId));
+      assert.equal(post.likesCount.toNumber(), 1);
+
+      await program.methods
+        .removeReaction(postId)
+        .accountsPartial(accounts)
+        .signers([fan])
+        .rpc();
+      assert.deepEqual(await counts(), [0, 0, 0, 0, 0]);
+      assert.isNull(await provider.connection.getAccountInfo(reactionPda));
+    });
+  });
+
+  describe("collaborations", () => {
+    const invite = async (
+      author: Keypair,
+      collaborators: Keypair[],
+      shareBps = 0
+    ) => {
+      const state = await program.account.programState.fetch(programStatePda);
+      const postId = state.postCount.addn(1);
+      await program.methods
+        .createCollaborationPost(
+          collaborators.map((c) => ({ key: c.publicKey, shareBps })),
+          "joint post",
+          null
+        )
+        .accountsPartial({
+          programState: programStatePda,
+          authorProfile: profilePda(author.publicKey),
+          post: postPda(postId),
+          author: author.publicKey,
+        })
+        .signers([author])
+        .rpc();
+      return postId;
+    };
+
+    const postsCount = async (user: Keypair) =>
+      (
+        await program.account.userProfile.fetch(profilePda(user.publicKey))
+      ).postsCount.toNumber();
+
+    const respond = (
+      method: "acceptCollaboration" | "rejectCollaboration",
+      postId: anchor.BN,
+      collaborator: Keypair
+    ) =>
+      program.methods[method](postId)
+        .accountsPartial({
+          programState: programStatePda,
+          post: postPda(postId),
+          collaborator: collaborator.publicKey,
+        })
+        .signers([collaborator])
+        .rpc();
+
+    it("only becomes a collaboration once the collaborator accepts", async () => {
+      const author = await newUser();
+      const collaborator = await newUser();
+      const stranger = await newUser();
+      await createProfile(author, "collab_author");
+      await createProfile(collaborator, "collab_partner");
+      await createProfile(stranger, "collab_stranger");
+
+      const postId = await invite(author, [collaborator]);
+      let post = await program.account.post.fetch(postPda(postId));
+      assert.deepEqual(post.collaborators[0].status, { pending: {} });
+      assert.isFalse(post.isCollaboration);
+
+      // Pending collaborators cannot manage the post yet
+      await expectError(
+        deletePost(collaborator, postId),
+        "CannotDeleteOthersPost"
+      );
+
+      await expectError(
+        respond("acceptCollaboration", postId, stranger),
+        "NotInvitedCollaborator"
+      );
+
+      await respond("acceptCollaboration", postId, collaborator);
+      post = await program.account.post.fetch(postPda(postId));
+      assert.deepEqual(post.collaborators[0].status, { accepted: {} });
+      assert.isTrue(post.isCollaboration);
+
+      const profile = await program.account.userProfile.fetch(
+        profilePda(collaborator.publicKey)
+      );
+      assert.equal(profile.postsCount.toNumber(), 1);
+
+      await expectError(
+        respond("rejectCollaboration", postId, collaborator),
+        "CollaborationNotPending"
+      );
+    });
+
+    it("keeps a rejected invite as a solo post", async () => {
+      const author = await newUser();
+      const collaborator = await newUser();
+      await createProfile(author, "solo_author");
+      await createProfile(collaborator, "solo_decliner");
+
+      const postId = await invite(author, [collaborator]);
+      await respond("rejectCollaboration", postId, collaborator);
+
+      const post = await program.account.post.fetch(postPda(postId));
+      assert.deepEqual(post.collaborators[0].status, { rejected: {} });
+      assert.isFalse(post.isCollaboration);
+      assert.isFalse(post.isDeleted);
+
+      await expectError(
+        respond("acceptCollaboration", postId, collaborator),
+        "CollaborationNotPending"
+      );
+    });
+
+    it("tracks each co-author's approval separately", async () => {
+      const author = await newUser();
+      const first = await newUser();
+      const second = await newUser();
+      await createProfile(author, "team_author");
+      await createProfile(first, "team_first");
+      await createProfile(second, "team_second");
+
+      await expectError(
+        invite(author, [first, first]),
+        "DuplicateCollaborator"
+      );
+      const crowd = await Promise.all([...Array(6)].map(() => newUser()));
+      await expectError(invite(author, crowd), "TooManyCollaborators");
+
+      const postId = await invite(author, [first, second]);
+      await respond("acceptCollaboration", postId, first);
+      await respond("rejectCollaboration", postId, second);
+
+      const post = await program.account.post.fetch(postPda(postId));
+      assert.isTrue(post.isCollaboration);
+      assert.deepEqual(
+        post.collaborators.map((c) => [c.key.toBase58(), c.status]),
+        [
+          [first.publicKey.toBase58(), { accepted: {} }],
+          [second.publicKey.toBase58(), { rejected: {} }],
+        ]
+      );
+
+      // Only the co-author who accepted may delete the post
+      await expectError(
+        deletePost(second, postId, [first]),
+        "CannotDeleteOthersPost"
+      );
+      await expectError(
+        deletePost(first, postId),
+        "CollaboratorAccountsMismatch"
+      );
+
+      assert.equal(await postsCount(author), 1);
+      assert.equal(await postsCount(first), 1);
+      await deletePost(first, postId, [first]);
+
+      // Deleting takes the post off every owner's count
+      assert.equal(await postsCount(author), 0);
+      assert.equal(await postsCount(first), 0);
+      assert.equal(await postsCount(second), 0);
+    });
+
+    it("splits tips between the author and accepted co-authors", async () => {
+      const author = await newUser();
+      const first = await newUser();
+      const second = await newUser();
+      const donor = await newUser();
+      await createProfile(author, "split_author");
+      await createProfile(first, "split_first");
+      await createProfile(second, "split_second");
+
+      await expectError(
+        invite(author, [first, second], 6_000),
+        "InvalidRevenueShare"
+      );
+
+      // 30% each; the second co-author never accepts so the author keeps theirs
+      const postId = await invite(author, [first, second], 3_000);
+      await respond("acceptCollaboration", postId, first);
+
+      const amount = new anchor.BN(LAMPORTS_PER_SOL / 10);
+      const fee = amount.muln(500).divn(10_000);
+      const net = amount.sub(fee);
+      const firstShare = net.muln(3_000).divn(10_000);
+
+      const tip = async (remaining: PublicKey[]) =>
+        program.methods
+          .tipPost(postId, amount, null, false)
+          .accountsPartial({
+            programState: programStatePda,
+            post: postPda(postId),
+            donationSummary: donationSummaryPda(
+              donor.publicKey,
+              author.publicKey
+            ),
+            donation: donationPda(
+              author.publicKey,
+              await nextDonationId(author.publicKey)
+            ),
+            authorProfile: profilePda(author.publicKey),
+            authorWallet: author.publicKey,
+            treasury: treasuryPda,
+            donor: donor.publicKey,
+          })
+          .remainingAccounts(
+            remaining.map((pubkey) => ({
+              pubkey,
+              isSigner: false,
+              isWritable: true,
+            }))
+          )
+          .signers([donor])
+          .rpc();
+
+      await expectError(tip([]), "CollaboratorAccountsMismatch");
+
+      const authorBefore = await balance(author.publicKey);
+      const firstBefore = await balance(first.publicKey);
+      const secondBefore = await balance(second.publicKey);
+      await tip([first.publicKey, profilePda(first.publicKey)]);
+
+      assert.equal(
+        (await balance(first.publicKey)) - firstBefore,
+        firstShare.toNumber()
+      );
+      assert.equal(
+        (await balance(author.publicKey)) - authorBefore,
+        net.sub(firstShare).toNumber()
+      );
+      assert.equal(await balance(second.publicKey), secondBefore);
+
+      const firstProfile = await program.account.userProfile.fetch(
+        profilePda(first.publicKey)
+      );
+      assert.equal(
+        firstProfile.totalDonationsReceived.toNumber(),
+        firstShare.toNumber()
+      );
+      const authorProfile = await program.account.userProfile.fetch(
+        profilePda(author.publicKey)
+      );
+      assert.equal(
+        authorProfile.totalDonationsReceived.toNumber(),
+        net.sub(firstShare).toNumber()
+      );
+
+      // The receipt and the post both record the tip
+      const post = await program.account.post.fetch(postPda(postId));
+      assert.equal(post.tipsTotal.toNumber(), amount.toNumber());
+      const receipt = await program.account.donation.fetch(
+        donationPda(author.publicKey, 1)
+      );
+      assert.equal(receipt.postId.toNumber(), postId.toNumber());
+      assert.equal(receipt.amount.toNumber(), amount.toNumber());
+    });
+  });
+
+  describe("comments", () => {
+    it("assigns sequential comment ids per post", async () => {
+      const user = await newUser();
+      await createProfile(user, "commenter_seq");
+      const postId = await createPost(user);
+
+      assert.equal((await createComment(user, postId, "one")).toNumber(), 1);
+      assert.equal((await createComment(user, postId, "two")).toNumber(), 2);
+
+      const second = await program.account.comment.fetch(
+        commentPda(postId, 2)
+      );
+      assert.equal(second.commentId.toNumber(), 2);
+      assert.equal(second.content, "two");
+
+      const post = await program.account.post.fetch(postPda(postId));
+      assert.equal(post.commentIdCounter.toNumber(), 2);
+      assert.equal(post.commentsCount.toNumber(), 2);
+    });
+
+    it("threads replies under a parent comment up to the depth cap", async () => {
+      const user = await newUser();
+      await createProfile(user, "thread_starter");
+      const postId = await createPost(user);
+      const otherPostId = await createPost(user);
+
+      let parentId = await createComment(user, postId, "depth 0");
+      const rootId = parentId;
+      for (let depth = 1; depth <= 4; depth++) {
+        parentId = await createComment(user, postId, `depth ${depth}`, parentId);
+      }
+
+      const root = await program.account.comment.fetch(
+        commentPda(postId, rootId)
+      );
+      assert.isNull(root.parentCommentId);
+      assert.equal(root.repliesCount.toNumber(), 1);
+
+      const deepest = await program.account.comment.fetch(
+        commentPda(postId, parentId)
+      );
+      assert.equal(deepest.depth, 4);
+      assert.equal(deepest.parentCommentId.toNumber(), parentId.toNumber() - 1);
+
+      await expectError(
+        createComment(user, postId, "too deep", parentId),
+        "CommentDepthExceeded"
+      );
+
+      // The parent is derived under this post, so another post's comment
+      // cannot stand in for it
+      const foreignId = await createComment(user, otherPostId, "elsewhere");
+      const post = await program.account.post.fetch(postPda(postId));
+      await expectError(
+        program.methods
+          .createComment(postId, "wrong thread", foreignId)
+          .accountsPartial({
+            programState: programStatePda,
+            post: postPda(postId),
+            comment: commentPda(postId, post.commentIdCounter.addn(1)),
+            parentComment: commentPda(otherPostId, foreignId),
+            user: user.publicKey,
+          })
+          .signers([user])
+          .rpc(),
+        "ConstraintSeeds"
+      );
+    });
+
+    it("rejects a reply to a deleted comment", async () => {
+      const user = await newUser();
+      await createProfile(user, "thread_closer");
+      const postId = await createPost(user);
+      const parentId = await createComment(user, postId, "gone soon");
+      await deleteComment(user, postId, parentId);
+
+      await expectError(
+        createComment(user, postId, "too late", parentId),
+        "CommentNotFound"
+      );
+
+      // A parent id without the parent account is still refused
+      const post = await program.account.post.fetch(postPda(postId));
+      await expectError(
+        program.methods
+          .createComment(postId, "no parent", parentId)
+          .accountsPartial({
+            programState: programStatePda,
+            post: postPda(postId),
+            comment: commentPda(postId, post.commentIdCounter.addn(1)),
+            parentComment: null,
+            user: user.publicKey,
+          })
+          .signers([user])
+          .rpc(),
+        "ParentCommentMismatch"
+      );
+    });
+
+    it("deletes a comment at the address it was created at", async () => {
+      const user = await newUser();
+      await createProfile(user, "commenter_one");
+      const postId = await createPost(user);
+      const commentId = await createComment(user, postId, "first!");
+
+      let post = await program.account.post.fetch(postPda(postId));
+      assert.equal(post.commentsCount.toNumber(), 1);
+
+      await deleteComment(user, postId, commentId);
+
+      assert.isNull(
+        await provider.connection.getAccountInfo(commentPda(postId, commentId))
+      );
+      post = await program.account.post.fetch(postPda(postId));
+      assert.equal(post.commentsCount.toNumber(), 0);
+    });
+
+    it("likes and unlikes a comment once per user", async () => {
+      const author = await newUser();
+      const fan = await newUser();
+      await createProfile(author, "comment_liked");
+      const postId = await createPost(author);
+      const commentId = await createComment(author, postId, "like me");
+      const [commentLikePda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("comment_like"),
+          fan.publicKey.toBuffer(),
+          u64(postId),
+          u64(commentId),
+        ],
+        program.programId
+      );
+      const accounts = {
+        commentLike: commentLikePda,
+        comment: commentPda(postId, commentId),
+        user: fan.publicKey,
+      };
+
+      await program.methods
+        .likeComment(postId, commentId)
+        .accountsPartial(accounts)
+        .signers([fan])
+        .rpc();
+      let comment = await program.account.comment.fetch(
+        commentPda(postId, commentId)
+      );
+      assert.equal(comment.likesCount.toNumber(), 1);
+
+      // The CommentLike PDA prevents liking twice
+      try {
+        await program.methods
+          .likeComment(postId, commentId)
+          .accountsPartial(accounts)
+          .signers([fan])
+          .rpc();
+        assert.fail("expected the second like to fail");
+      } catch (err) {
+        assert.notEqual(err.message, "expected the second like to fail");
+      }
+
+      await program.methods
+        .unlikeComment(postId, commentId)
+        .accountsPartial(accounts)
+        .signers([fan])
+        .rpc();
+      comment = await program.account.comment.fetch(
+        commentPda(postId, commentId)
+      );
+      assert.equal(comment.likesCount.toNumber(), 0);
+      assert.isNull(await provider.connection.getAccountInfo(commentLikePda));
+    });
+
+    it("keeps reply counts and comment likes consistent on delete", async () => {
+      const author = await newUser();
+      const fan = await newUser();
+      await createProfile(author, "comment_cleanup");
+      const postId = await createPost(author);
+      const parentId = await createComment(author, postId, "parent");
+      const replyId = await createComment(author, postId, "reply", parentId);
+      const [commentLikePda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("comment_like"),
+          fan.publicKey.toBuffer(),
+          u64(postId),
+          u64(replyId),
+        ],
+        program.programId
+      );
+      const accounts = {
+        commentLike: commentLikePda,
+        comment: commentPda(postId, replyId),
+        user: fan.publicKey,
+      };
+
+      await program.methods
+        .likeComment(postId, replyId)
+        .accountsPartial(accounts)
+        .signers([fan])
+        .rpc();
+
+      await deleteComment(author, postId, replyId);
+      const parent = await program.account.comment.fetch(
+        commentPda(postId, parentId)
+      );
+      assert.equal(parent.repliesCount.toNumber(), 0);
+
+      // The like can still be withdrawn after its comment is gone
+      await program.methods
+        .unlikeComment(postId, replyId)
+        .accountsPartial(accounts)
+        .signers([fan])
+        .rpc();
+      assert.isNull(await provider.connection.getAccountInfo(commentLikePda));
+    });
+
+    it("lets only the commenter edit a comment", async () => {
+      const author = await newUser();
+      const commenter = await newUser();
+      await createProfile(author, "edited_post");
+      const postId = await createPost(author);
+      const commentId = await createComment(commenter, postId, "tpyo");
+
+      const edit = (user: Keypair, content: string) =>
+        program.methods
+          .editComment(postId, commentId, content)
+          .accountsPartial({
+            comment: commentPda(postId, commentId),
+            user: user.publicKey,
+          })
+          .signers([user])
+          .rpc();
+
+      await expectError(edit(author, "hijacked"), "NotCommentAuthor");
+
+      await edit(commenter, "typo");
+      const comment = await program.account.comment.fetch(
+        commentPda(postId, commentId)
+      );
+      assert.equal(comment.content, "typo");
+      assert.isTrue(comment.isEdited);
+    });
+
+    it("lets the post author hide and remove comments", async () => {
+      const author = await newUser();
+      const troll = await newUser();
+      await createProfile(author, "moderator_one");
+      const postId = await createPost(author);
+      const commentId = await createComment(troll, postId, "spam");
+
+      const setHidden = (user: Keypair, hidden: boolean) =>
+        program.methods
+          .setCommentHidden(postId, commentId, hidden)
+          .accountsPartial({
+            comment: commentPda(postId, commentId),
+            post: postPda(postId),
+            user: user.publicKey,
+          })
+          .signers([user])
+          .rpc();
+
+      await expectError(setHidden(troll, false), "NotPostOwner");
+
+      await setHidden(author, true);
+      const comment = await program.account.comment.fetch(
+        commentPda(postId, commentId)
+      );
+      assert.isTrue(comment.isHidden);
+
+      const stranger = await newUser();
+      await expectError(
+        deleteComment(stranger, postId, commentId),
+        "NotCommentAuthor"
+      );
+
+      const trollBefore = await balance(troll.publicKey);
+      await deleteComment(author, postId, commentId);
+      assert.isNull(
+        await provider.connection.getAccountInfo(commentPda(postId, commentId))
+      );
+      // Rent goes back to the commenter, not the moderator
+      assert.isAbove(await balance(troll.publicKey), trollBefore);
+    });
+  });
+
+  describe("donations", () => {
+    it("pays the creator wallet and the treasury", async () => {
+      const creator = await newUser();
+      const donor = await newUser();
+      await createProfile(creator, "donee_one");
+
+      const amount = new anchor.BN(LAMPORTS_PER_SOL / 10);
+      const fee = amount.muln(500).divn(10_000);
+
+      const creatorBefore = await balance(creator.publicKey);
+      const treasuryBefore = await balance(treasuryPda);
+      const stateBefore = await program.account.programState.fetch(
+        programStatePda
+      );
+
+      await donate(donor, creator.publicKey, amount);
+
+      assert.equal(
+        (await balance(creator.publicKey)) - creatorBefore,
+        amount.sub(fee).toNumber()
+      );
+      assert.equal(
+        (await balance(treasuryPda)) - treasuryBefore,
+        fee.toNumber()
+      );
+
+      const profile = await program.account.userProfile.fetch(
+        profilePda(creator.publicKey)
+      );
+      assert.equal(
+        profile.totalDonationsReceived.toNumber(),
+        amount.sub(fee).toNumber()
+      );
+
+      const state = await program.account.programState.fetch(programStatePda);
+      assert.equal(
+        state.totalFeesCollected.sub(stateBefore.totalFeesCollected).toNumber(),
+        fee.toNumber()
+      );
+    });
+
+    it("rejects a wallet that does not match the creator", async () => {
+      const creator = await newUser();
+      const donor = await newUser();
+      await createProfile(creator, "donee_two");
+      const amount = new anchor.BN(LAMPORTS_PER_SOL / 10);
+
+      await expectError(
+        donate(donor, creator.publicKey, amount, donor.publicKey),
+        "InvalidCreatorWallet"
+      );
+    });
+
+    it("allows repeated donations and keeps a receipt for each", async () => {
+      const creator = await newUser();
+      const donor = await newUser();
+      await createProfile(creator, "donee_three");
+
+      const first = new anchor.BN(LAMPORTS_PER_SOL / 20);
+      const second = new anchor.BN(LAMPORTS_PER_SOL / 10);
+      await donate(donor, creator.publicKey, first);
+      await donate(donor, creator.publicKey, second);
+
+      const summary = await program.account.donationSummary.fetch(
+        donationSummaryPda(donor.publicKey, creator.publicKey)
+      );
+      assert.equal(summary.donationCount.toNumber(), 2);
+      assert.equal(summary.totalAmount.toNumber(), first.add(second).toNumber());
+
+      const receipts = await Promise.all(
+        [1, 2].map((id) =>
+          program.account.donation.fetch(
+            donationPda(creator.publicKey, id)
+          )
+        )
+      );
+      assert.deepEqual(
+        receipts.map((r) => r.donationId.toNumber()),
+        [1, 2]
+      );
+      assert.deepEqual(
+        receipts.map((r) => r.amount.toNumber()),
+        [first.toNumber(), second.toNumber()]
+      );
+    });
+
+    it("stores a donor message and hides anonymous donors", async () => {
+      const creator = await newUser();
+      const donor = await newUser();
+      await createProfile(creator, "donee_four");
+      const amount = new anchor.BN(LAMPORTS_PER_SOL / 20);
+
+      await expectError(
+        donate(donor, creator.publicKey, amount, creator.publicKey, {
+          message: "x".repeat(141),
+        }),
+        "DonationMessageTooLong"
+      );
+
+      await donate(donor, creator.publicKey, amount, creator.publicKey, {
+        message: "keep it up",
+      });
+      await donate(donor, creator.publicKey, amount, creator.publicKey, {
+        isAnonymous: true,
+      });
+
+      const [named, anonymous] = await Promise.all(
+        [1, 2].map((id) =>
+          program.account.donation.fetch(
+            donationPda(creator.publicKey, id)
+          )
+        )
+      );
+      assert.equal(named.message, "keep it up");
+      assert.isFalse(named.isAnonymous);
+      assert.ok(named.donor.equals(donor.publicKey));
+
+      assert.isNull(anonymous.message);
+      assert.isTrue(anonymous.isAnonymous);
+      assert.ok(anonymous.donor.equals(PublicKey.default));
+
+      // Anonymous gifts never reach the donor/creator summary
+      await donate(donor, creator.publicKey, amount, creator.publicKey);
+      const summary = await program.account.donationSummary.fetch(
+        donationSummaryPda(donor.publicKey, creator.publicKey)
+      );
+      assert.equal(summary.donationCount.toNumber(), 2);
+      assert.equal(summary.totalAmount.toNumber(), amount.muln(2).toNumber());
+
+      const shy = await newUser();
+      await donate(shy, creator.publicKey, amount, creator.publicKey, {
+        isAnonymous: true,
+      });
+      assert.isNull(
+        await program.account.donationSummary.fetchNullable(
+          donationSummaryPda(shy.publicKey, creator.publicKey)
+        )
+      );
+      const profile = await program.account.userProfile.fetch(
+        profilePda(creator.publicKey)
+      );
+      assert.equal(profile.donationsReceivedCount.toNumber(), 4);
+    });
+
+    it("tips SPL tokens with the platform fee split", async () => {
+      const creator = await newUser();
+      const donor = await newUser();
+      await createProfile(creator, "token_donee");
+
+      const mint = await createTestMint(donor.publicKey, 10_000_000);
+      const amount = new anchor.BN(2_000_000);
+      const fee = amount.muln(500).divn(10_000);
+      const donationId = await nextDonationId(creator.publicKey);
+      const [tipTotalPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("token_tip_total"),
+          creator.publicKey.toBuffer(),
+          mint.toBuffer(),
+        ],
+        program.programId
+      );
+
+      await donateToken(donor, creator.publicKey, mint, amount);
+
+      const creatorTokens = await tokenBalance(
+        tokenAccount(mint, creator.publicKey)
+      );
+      const treasuryTokens = await tokenBalance(tokenAccount(mint, treasuryPda));
+      assert.equal(creatorTokens.toNumber(), amount.sub(fee).toNumber());
+      assert.equal(treasuryTokens.toNumber(), fee.toNumber());
+
+      const tipTotal = await program.account.tokenTipTotal.fetch(tipTotalPda);
+      assert.ok(tipTotal.mint.equals(mint));
+      assert.equal(tipTotal.totalReceived.toNumber(), amount.sub(fee).toNumber());
+      assert.equal(tipTotal.tipCount.toNumber(), 1);
+
+      // Token fees are tracked per mint, not in the SOL totals
+      const treasuryTotal = await program.account.treasuryTokenTotal.fetch(
+        treasuryTokenTotalPda(mint)
+      );
+      assert.ok(treasuryTotal.mint.equals(mint));
+      assert.equal(treasuryTotal.feesCollected.toNumber(), fee.toNumber());
+      assert.equal(treasuryTotal.feesWithdrawn.toNumber(), 0);
+
+      const receipt = await program.account.donation.fetch(
+        donationPda(creator.publicKey, donationId)
+      );
+      assert.ok(receipt.mint.equals(mint));
+      assert.equal(receipt.amount.toNumber(), amount.toNumber());
+    });
+  });
+
+  describe("treasury", () => {
+    const feeRecipient = Keypair.generate();
+
+    before(async () => {
+      await airdrop(feeRecipient.publicKey, 1);
+      await program.methods
+        .updatePlatformAddress(feeRecipient.publicKey)
+        .accounts({ admin: deployer.publicKey })
+        .rpc();
+
+      // Make sure there are fees to withdraw
+      const creator = await newUser();
+      const donor = await newUser();
+      await createProfile(creator, "treasury_donee");
+      await donate(donor, creator.publicKey, new anchor.BN(LAMPORTS_PER_SOL));
+    });
+
+    after(async () => {
+      await program.methods
+        .updatePlatformAddress(deployer.publicKey)
+        .accounts({ admin: deployer.publicKey })
+        .rpc();
+    });
+
+    const withdraw = (amount: anchor.BN, admin?: Keypair) =>
+      program.methods
+        .withdrawTreasury(amount)
+        .accountsPartial({
+          treasury: treasuryPda,
+          recipient: feeRecipient.publicKey,
+          admin: admin?.publicKey ?? deployer.publicKey,
+        })
+        .signers(admin ? [admin] : [])
+        .rpc();
+
+    it("lets the admin withdraw SOL fees to the fee recipient", async () => {
+      const amount = new anchor.BN(1_000_000);
+      const recipientBefore = await balance(feeRecipient.publicKey);
+      const treasuryBefore = await balance(treasuryPda);
+
+      await withdraw(amount);
+
+      assert.equal(
+        (await balance(feeRecipient.publicKey)) - recipientBefore,
+        amount.toNumber()
+      );
+      assert.equal(
+        treasuryBefore - (await balance(treasuryPda)),
+        amount.toNumber()
+      );
+    });
+
+    it("cannot drain the treasury below rent exemption", async () => {
+      const rentExempt =
+        await provider.connection.getMinimumBalanceForRentExemption(0);
+      const available = (await balance(treasuryPda)) - rentExempt;
+
+      await expectError(
+        withdraw(new anchor.BN(available + 1)),
+        "InsufficientTreasuryBalance"
+      );
+
+      await withdraw(new anchor.BN(available));
+      assert.equal(await balance(treasuryPda), rentExempt);
+    });
+
+    it("rejects withdrawals by anyone but the admin", async () => {
+      const intruder = await newUser();
+      await expectError(
+        withdraw(new anchor.BN(1), intruder),
+        "UnauthorizedAdmin"
+      );
+    });
+
+    it("lets the admin withdraw SPL fees", async () => {
+      const creator = await newUser();
+      const donor = await newUser();
+      await createProfile(creator, "treasury_token");
+      const mint = await createTestMint(donor.publicKey, 10_000_000);
+      await donateToken(donor, creator.publicKey, mint, new anchor.BN(4_000_000));
+
+      const treasuryAta = tokenAccount(mint, treasuryPda);
+      const recipientAta = tokenAccount(mint, feeRecipient.publicKey);
+      const fees = await tokenBalance(treasuryAta);
+
+      await program.methods
+        .withdrawTreasuryToken(fees)
+        .accountsPartial({
+          treasury: treasuryPda,
+          recipient: feeRecipient.publicKey,
+          mint,
+          treasuryTokenAccount: treasuryAta,
+          treasuryTokenTotal: treasuryTokenTotalPda(mint),
+          recipientTokenAccount: recipientAta,
+          admin: deployer.publicKey,
+        })
+        .rpc();
+
+      const received = await tokenBalance(recipientAta);
+      assert.equal(received.toNumber(), fees.toNumber());
+      const remaining = await tokenBalance(treasuryAta);
+      assert.equal(remaining.toNumber(), 0);
+
+      const treasuryTotal = await program.account.treasuryTokenTotal.fetch(
+        treasuryTokenTotalPda(mint)
+      );
+      assert.equal(treasuryTotal.feesCollected.toNumber(), fees.toNumber());
+      assert.equal(treasuryTotal.feesWithdrawn.toNumber(), fees.toNumber());
+    });
   });
 });